@@ -0,0 +1,321 @@
+use aoc_common::{Answer, Part};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often the elapsed times of running jobs are refreshed.
+const REFRESH: Duration = Duration::from_millis(100);
+
+/// One part of one day, solved on its own so slow parts don't hold up others.
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Queued,
+    Running {
+        since: Instant,
+    },
+    Solved {
+        answer: Answer,
+        elapsed: Duration,
+    },
+    Unsolved {
+        elapsed: Duration,
+    },
+    Failed {
+        reason: String,
+        elapsed: Duration,
+    },
+    /// Never started, usually for lack of an input.
+    Skipped {
+        reason: String,
+    },
+}
+
+impl Status {
+    fn is_done(&self) -> bool {
+        !matches!(self, Status::Queued | Status::Running { .. })
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Queued => "queued",
+            Status::Running { .. } => "running",
+            Status::Solved { .. } => "solved",
+            Status::Unsolved { .. } => "unsolved",
+            Status::Failed { .. } => "FAILED",
+            Status::Skipped { .. } => "skipped",
+        }
+    }
+
+    fn elapsed(&self, now: Instant) -> Option<Duration> {
+        match self {
+            Status::Queued | Status::Skipped { .. } => None,
+            Status::Running { since } => Some(now.duration_since(*since)),
+            Status::Solved { elapsed, .. }
+            | Status::Unsolved { elapsed }
+            | Status::Failed { elapsed, .. } => Some(*elapsed),
+        }
+    }
+}
+
+enum Event {
+    Started(usize, Instant),
+    Finished(usize, Status),
+}
+
+/// Runs every queued job through `solve` on `threads` threads. `on_update` is
+/// called on this thread whenever a job starts or finishes, and regularly in
+/// between. A panic in `solve` fails its job, not the whole run.
+pub fn run(
+    jobs: &mut [Job],
+    threads: usize,
+    solve: impl Fn(u8, Part) -> Result<Option<Answer>, String> + Sync,
+    mut on_update: impl FnMut(&[Job]),
+) {
+    let queue: Vec<_> = (0..jobs.len())
+        .filter(|i| jobs[*i].status == Status::Queued)
+        .map(|i| (i, jobs[i].day, jobs[i].part))
+        .collect();
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    // The panic is reported in the table, the default message would only mess
+    // it up.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    thread::scope(|s| {
+        for _ in 0..threads.clamp(1, queue.len().max(1)) {
+            let (tx, queue, next, solve) = (tx.clone(), &queue, &next, &solve);
+            s.spawn(move || {
+                while let Some(&(i, day, part)) = queue.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let start = Instant::now();
+                    let _ = tx.send(Event::Started(i, start));
+                    let res = panic::catch_unwind(AssertUnwindSafe(|| solve(day, part)));
+                    let elapsed = start.elapsed();
+                    let status = match res {
+                        Ok(Ok(Some(answer))) => Status::Solved { answer, elapsed },
+                        Ok(Ok(None)) => Status::Unsolved { elapsed },
+                        Ok(Err(reason)) => Status::Failed { reason, elapsed },
+                        Err(payload) => Status::Failed {
+                            reason: format!("panicked: {}", panic_message(&*payload)),
+                            elapsed,
+                        },
+                    };
+                    let _ = tx.send(Event::Finished(i, status));
+                }
+            });
+        }
+        drop(tx);
+
+        on_update(jobs);
+        loop {
+            match rx.recv_timeout(REFRESH) {
+                Ok(Event::Started(i, since)) => jobs[i].status = Status::Running { since },
+                Ok(Event::Finished(i, status)) => jobs[i].status = status,
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+            on_update(jobs);
+        }
+    });
+
+    panic::set_hook(hook);
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(msg), _) => msg,
+        (_, Some(msg)) => msg,
+        (None, None) => "unknown cause",
+    }
+}
+
+/// A count of finished jobs, and a row for every running one.
+pub fn progress(jobs: &[Job], now: Instant) -> String {
+    let done = jobs.iter().filter(|j| j.status.is_done()).count();
+    let failed = jobs
+        .iter()
+        .filter(|j| matches!(j.status, Status::Failed { .. }))
+        .count();
+    let mut out = format!("{}/{} done, {} failed\n", done, jobs.len(), failed);
+    out += &format!(
+        "{:>3}  {:>4}  {:<8}  {:>10}\n",
+        "Day", "Part", "Status", "Elapsed"
+    );
+    for job in jobs {
+        if let Status::Running { .. } = job.status {
+            out += &row(job, now);
+            out += "\n";
+        }
+    }
+    out
+}
+
+/// Every job with its answer, or why it has none, and the totals.
+pub fn summary(jobs: &[Job], total: Duration) -> String {
+    let now = Instant::now();
+    let mut out = format!(
+        "{:>3}  {:>4}  {:<8}  {:>10}  Answer\n",
+        "Day", "Part", "Status", "Elapsed"
+    );
+    let mut counts = [0; 4];
+    for job in jobs {
+        out += &row(job, now);
+        match &job.status {
+            // Multi-line answers (like CRT screens) start on their own line.
+            Status::Solved { answer, .. } => match answer.to_string() {
+                a if a.contains('\n') => out += &format!("\n{}", a),
+                a => out += &format!("  {}", a),
+            },
+            Status::Failed { reason, .. } | Status::Skipped { reason } => {
+                out += &format!("  ({})", reason)
+            }
+            _ => {}
+        }
+        out += "\n";
+
+        match job.status {
+            Status::Solved { .. } => counts[0] += 1,
+            Status::Unsolved { .. } => counts[1] += 1,
+            Status::Failed { .. } => counts[2] += 1,
+            _ => counts[3] += 1,
+        }
+    }
+    out += &format!(
+        "{} solved, {} unsolved, {} failed, {} skipped in {:.1?}\n",
+        counts[0], counts[1], counts[2], counts[3], total
+    );
+    out
+}
+
+fn row(job: &Job, now: Instant) -> String {
+    let elapsed = match job.status.elapsed(now) {
+        Some(e) => format!("{:.1?}", e),
+        None => String::new(),
+    };
+    format!(
+        "{:>3}  {:>4}  {:<8}  {:>10}",
+        format!("{:02}", job.day),
+        job.part.to_string(),
+        job.status.label(),
+        elapsed
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jobs() -> Vec<Job> {
+        (1..=3)
+            .flat_map(|day| {
+                Part::ALL.map(|part| Job {
+                    day,
+                    part,
+                    status: Status::Queued,
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_run() {
+        let mut jobs = jobs();
+        jobs[5].status = Status::Skipped {
+            reason: "no input".to_string(),
+        };
+        let mut updates = 0;
+        run(
+            &mut jobs,
+            4,
+            |day, part| match (day, part) {
+                (1, _) => Ok(Some(Answer::UInt(day as u64))),
+                (2, Part::One) => panic!("day {} is broken", day),
+                (2, Part::Two) => Err("failed to parse input".to_string()),
+                _ => Ok(None),
+            },
+            |_| updates += 1,
+        );
+
+        assert!(updates >= 2);
+        assert!(matches!(
+            &jobs[0].status,
+            Status::Solved {
+                answer: Answer::UInt(1),
+                ..
+            }
+        ));
+        assert!(matches!(
+            &jobs[2].status,
+            Status::Failed { reason, .. } if reason == "panicked: day 2 is broken"
+        ));
+        assert!(matches!(
+            &jobs[3].status,
+            Status::Failed { reason, .. } if reason == "failed to parse input"
+        ));
+        assert!(matches!(jobs[4].status, Status::Unsolved { .. }));
+        assert!(matches!(jobs[5].status, Status::Skipped { .. }));
+    }
+
+    #[test]
+    fn test_slow_jobs_dont_block() {
+        let mut jobs = jobs();
+        let finished = std::sync::Mutex::new(Vec::new());
+        run(
+            &mut jobs,
+            2,
+            |day, part| {
+                if (day, part) == (1, Part::One) {
+                    thread::sleep(Duration::from_millis(200));
+                }
+                finished.lock().unwrap().push((day, part));
+                Ok(None)
+            },
+            |_| {},
+        );
+        // The other thread got through everything else in the meantime.
+        let finished = finished.into_inner().unwrap();
+        assert_eq!(finished.len(), 6);
+        assert_eq!(finished.last(), Some(&(1, Part::One)));
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut jobs = jobs();
+        jobs.truncate(3);
+        jobs[0].status = Status::Solved {
+            answer: Answer::UInt(24000),
+            elapsed: Duration::from_micros(1200),
+        };
+        jobs[1].status = Status::Failed {
+            reason: "panicked: oops".to_string(),
+            elapsed: Duration::from_millis(3),
+        };
+        jobs[2].status = Status::Skipped {
+            reason: "no input".to_string(),
+        };
+        assert_eq!(
+            summary(&jobs, Duration::from_millis(5)),
+            concat!(
+                "Day  Part  Status       Elapsed  Answer\n",
+                " 01     1  solved         1.2ms  24000\n",
+                " 01     2  FAILED         3.0ms  (panicked: oops)\n",
+                " 02     1  skipped               (no input)\n",
+                "1 solved, 0 unsolved, 1 failed, 1 skipped in 5.0ms\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use crate::days::DayResult;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Anything faster than this is too noisy to call a regression.
+const NOISE_FLOOR_US: u64 = 1000;
+
+/// The fastest timings of a day over all benchmark runs, in microseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub parse_us: u64,
+    pub part_1_us: u64,
+    pub part_2_us: u64,
+}
+
+impl Timing {
+    /// Takes the minimum of every stage, since that's the least disturbed by noise.
+    pub fn from_runs(day: u8, runs: &[DayResult]) -> Self {
+        let fastest = |f: &dyn Fn(&DayResult) -> Duration| {
+            runs.iter().map(f).min().unwrap_or_default().as_micros() as u64
+        };
+        Timing {
+            day,
+            parse_us: fastest(&|r| r.parse_time),
+            part_1_us: fastest(&|r| r.parts[0].elapsed),
+            part_2_us: fastest(&|r| r.parts[1].elapsed),
+        }
+    }
+
+    pub fn total_us(&self) -> u64 {
+        self.parse_us + self.part_1_us + self.part_2_us
+    }
+
+    fn stages(&self) -> [(Stage, u64); 3] {
+        [
+            (Stage::Parse, self.parse_us),
+            (Stage::Part1, self.part_1_us),
+            (Stage::Part2, self.part_2_us),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Parse,
+    #[serde(rename = "part_1")]
+    Part1,
+    #[serde(rename = "part_2")]
+    Part2,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part 1"),
+            Stage::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Regression {
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline_us: u64,
+    pub current_us: u64,
+}
+
+impl Regression {
+    pub fn slowdown_pct(&self) -> u64 {
+        (self.current_us - self.baseline_us) * 100 / self.baseline_us.max(1)
+    }
+}
+
+/// A benchmark run. The JSON form of a report doubles as a baseline file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Report {
+    pub timings: Vec<Timing>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regressions: Option<Vec<Regression>>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        let contents = fs::read_to_string(path).map_err(|e| BaselineError::Io {
+            path: path.to_path_buf(),
+            source: e,
+        })?;
+        serde_json::from_str(&contents).map_err(|e| BaselineError::Json {
+            path: path.to_path_buf(),
+            source: e,
+        })
+    }
+
+    /// Flags every stage that got more than `threshold_pct` percent slower than
+    /// in the baseline. Days missing from the baseline are never regressions.
+    pub fn compare(&mut self, baseline: &Report, threshold_pct: u64) {
+        let mut regressions = Vec::new();
+        for timing in &self.timings {
+            let Some(base) = baseline.timings.iter().find(|t| t.day == timing.day) else {
+                continue;
+            };
+            for ((stage, current_us), (_, baseline_us)) in
+                timing.stages().into_iter().zip(base.stages())
+            {
+                let limit = baseline_us + baseline_us * threshold_pct / 100;
+                if current_us > limit && current_us - baseline_us > NOISE_FLOOR_US {
+                    regressions.push(Regression {
+                        day: timing.day,
+                        stage,
+                        baseline_us,
+                        current_us,
+                    });
+                }
+            }
+        }
+        self.regressions = Some(regressions);
+    }
+
+    pub fn has_regressions(&self) -> bool {
+        self.regressions.as_ref().is_some_and(|r| !r.is_empty())
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn to_table(&self) -> String {
+        let mut out = format!(
+            "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}\n",
+            "Day", "Parse", "Part 1", "Part 2", "Total"
+        );
+        for t in &self.timings {
+            out += &format!(
+                "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}\n",
+                format!("{:02}", t.day),
+                fmt_us(t.parse_us),
+                fmt_us(t.part_1_us),
+                fmt_us(t.part_2_us),
+                fmt_us(t.total_us())
+            );
+        }
+        let total: u64 = self.timings.iter().map(Timing::total_us).sum();
+        out += &format!("{:>3}  {:>54}\n", "", fmt_us(total));
+
+        match &self.regressions {
+            None => {}
+            Some(r) if r.is_empty() => out += "\nNo regressions against the baseline\n",
+            Some(regressions) => {
+                out += "\nRegressions against the baseline:\n";
+                for r in regressions {
+                    out += &format!(
+                        "Day {:02}, {}: {} -> {} (+{}%)\n",
+                        r.day,
+                        r.stage,
+                        fmt_us(r.baseline_us),
+                        fmt_us(r.current_us),
+                        r.slowdown_pct()
+                    );
+                }
+            }
+        }
+        out
+    }
+}
+
+fn fmt_us(us: u64) -> String {
+    match us {
+        0..=999 => format!("{}µs", us),
+        1000..=999_999 => format!("{:.3}ms", us as f64 / 1e3),
+        _ => format!("{:.3}s", us as f64 / 1e6),
+    }
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BaselineError::Io { path, source } => {
+                write!(f, "Failed to read {}: {}", path.display(), source)
+            }
+            BaselineError::Json { path, source } => {
+                write!(f, "Failed to parse {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl Error for BaselineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BaselineError::Io { source, .. } => Some(source),
+            BaselineError::Json { source, .. } => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(day: u8, parse_us: u64, part_1_us: u64, part_2_us: u64) -> Timing {
+        Timing {
+            day,
+            parse_us,
+            part_1_us,
+            part_2_us,
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Report {
+            timings: vec![timing(1, 10, 5000, 20_000), timing(2, 10, 10, 10)],
+            regressions: None,
+        };
+        let mut report = Report {
+            timings: vec![
+                // Part 1 is 100% slower, part 2 only 10%.
+                timing(1, 10, 10_000, 22_000),
+                // Way slower, but still below the noise floor.
+                timing(2, 500, 10, 10),
+                // Not in the baseline.
+                timing(3, 1, 1, 1),
+            ],
+            regressions: None,
+        };
+        report.compare(&baseline, 20);
+        assert_eq!(
+            report.regressions,
+            Some(vec![Regression {
+                day: 1,
+                stage: Stage::Part1,
+                baseline_us: 5000,
+                current_us: 10_000
+            }])
+        );
+        assert!(report.has_regressions());
+        assert_eq!(report.regressions.as_ref().unwrap()[0].slowdown_pct(), 100);
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let report = Report {
+            timings: vec![timing(1, 1, 2, 3)],
+            regressions: None,
+        };
+        let parsed: Report = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(parsed.timings, report.timings);
+        assert_eq!(parsed.regressions, None);
+    }
+
+    #[test]
+    fn test_fmt_us() {
+        assert_eq!(fmt_us(12), "12µs");
+        assert_eq!(fmt_us(1234), "1.234ms");
+        assert_eq!(fmt_us(12_345_678), "12.346s");
+    }
+}
@@ -0,0 +1,198 @@
+use aoc_common::{Answer, ParamError, ParseError, Part, Solver, StreamError};
+use std::fmt;
+use std::io::{self, BufRead};
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+pub const DAYS: RangeInclusive<u8> = 1..=25;
+
+#[derive(Debug)]
+pub struct DayResult {
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+#[derive(Debug)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+pub enum RunError {
+    Param(ParamError),
+    Parse(ParseError),
+    Read(io::Error),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::Param(e) => write!(f, "{}", e),
+            RunError::Parse(e) => write!(f, "{}", e),
+            RunError::Read(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// Calls `$solve::<DayNN>(args..)` with the solver of `$day`. This is the
+/// only place that lists the days. Panics for days outside of `DAYS`.
+macro_rules! dispatch {
+    ($day:expr, $solve:ident($($arg:expr),*)) => {
+        match $day {
+            1 => $solve::<day_01::Day01>($($arg),*),
+            2 => $solve::<day_02::Day02>($($arg),*),
+            3 => $solve::<day_03::Day03>($($arg),*),
+            4 => $solve::<day_04::Day04>($($arg),*),
+            5 => $solve::<day_05::Day05>($($arg),*),
+            6 => $solve::<day_06::Day06>($($arg),*),
+            7 => $solve::<day_07::Day07>($($arg),*),
+            8 => $solve::<day_08::Day08>($($arg),*),
+            9 => $solve::<day_09::Day09>($($arg),*),
+            10 => $solve::<day_10::Day10>($($arg),*),
+            11 => $solve::<day_11::Day11>($($arg),*),
+            12 => $solve::<day_12::Day12>($($arg),*),
+            13 => $solve::<day_13::Day13>($($arg),*),
+            14 => $solve::<day_14::Day14>($($arg),*),
+            15 => $solve::<day_15::Day15>($($arg),*),
+            16 => $solve::<day_16::Day16>($($arg),*),
+            17 => $solve::<day_17::Day17>($($arg),*),
+            18 => $solve::<day_18::Day18>($($arg),*),
+            19 => $solve::<day_19::Day19>($($arg),*),
+            20 => $solve::<day_20::Day20>($($arg),*),
+            21 => $solve::<day_21::Day21>($($arg),*),
+            22 => $solve::<day_22::Day22>($($arg),*),
+            23 => $solve::<day_23::Day23>($($arg),*),
+            24 => $solve::<day_24::Day24>($($arg),*),
+            25 => $solve::<day_25::Day25>($($arg),*),
+            day => panic!("No solver for day {}", day),
+        }
+    };
+}
+
+/// A `name=value` pair to override one of a solver's parameters.
+pub type Param = (String, String);
+
+fn configured<S: Solver + Default>(params: &[Param]) -> Result<S, ParamError> {
+    let mut solver = S::default();
+    for (name, value) in params {
+        solver.set_param(name, value)?;
+    }
+    for (name, value) in solver.params() {
+        aoc_common::debug!("Using {} = {}", name, value);
+    }
+    Ok(solver)
+}
+
+fn run_solver<S: Solver + Default>(
+    input: &str,
+    parts: &[Part],
+    params: &[Param],
+) -> Result<DayResult, RunError> {
+    let solver = configured::<S>(params).map_err(RunError::Param)?;
+
+    let start = Instant::now();
+    let parsed = solver.parse(input).map_err(RunError::Parse)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = solver.solve(&parsed, part);
+            PartResult {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+    Ok(DayResult { parse_time, parts })
+}
+
+/// Solves the given parts of a day, after overriding any of its parameters.
+/// Panics for days outside of `DAYS`.
+pub fn run(day: u8, input: &str, parts: &[Part], params: &[Param]) -> Result<DayResult, RunError> {
+    dispatch!(day, run_solver(input, parts, params))
+}
+
+fn stream_solver<S: Solver + Default>(
+    input: &mut dyn BufRead,
+    parts: &[Part],
+    params: &[Param],
+) -> Result<Option<DayResult>, RunError> {
+    let solver = configured::<S>(params).map_err(RunError::Param)?;
+
+    let start = Instant::now();
+    let (p1, p2) = match solver.stream(input) {
+        None => return Ok(None),
+        Some(Ok(answers)) => answers,
+        Some(Err(StreamError::Parse(e))) => return Err(RunError::Parse(e)),
+        Some(Err(StreamError::Io(e))) => return Err(RunError::Read(e)),
+    };
+    let elapsed = start.elapsed();
+
+    // Both parts come out of the same pass, so they share its time.
+    let parts = parts
+        .iter()
+        .map(|&part| PartResult {
+            part,
+            answer: match part {
+                Part::One => Some(p1.clone()),
+                Part::Two => p2.clone(),
+            },
+            elapsed,
+        })
+        .collect();
+    Ok(Some(DayResult {
+        parse_time: Duration::ZERO,
+        parts,
+    }))
+}
+
+/// Solves the given parts of a day in a single pass over `input`, or returns
+/// `None` without reading anything if the day can't. Panics for days outside
+/// of `DAYS`.
+pub fn stream(
+    day: u8,
+    input: &mut dyn BufRead,
+    parts: &[Part],
+    params: &[Param],
+) -> Result<Option<DayResult>, RunError> {
+    dispatch!(day, stream_solver(input, parts, params))
+}
+
+fn generate_with<S: Solver + Default>(
+    seed: u64,
+    size: usize,
+    params: &[Param],
+) -> Result<Option<String>, ParamError> {
+    Ok(configured::<S>(params)?.generate(seed, size))
+}
+
+/// A random input for a day, or `None` if it has no generator. Panics for
+/// days outside of `DAYS`.
+pub fn generate(
+    day: u8,
+    seed: u64,
+    size: usize,
+    params: &[Param],
+) -> Result<Option<String>, ParamError> {
+    dispatch!(day, generate_with(seed, size, params))
+}
+
+fn report_with<S: Solver + Default>(
+    input: &str,
+    params: &[Param],
+) -> Result<Option<String>, RunError> {
+    let solver = configured::<S>(params).map_err(RunError::Param)?;
+    let parsed = solver.parse(input).map_err(RunError::Parse)?;
+    Ok(solver.report(&parsed))
+}
+
+/// The day's report on its input, or `None` if it has none. Panics for days
+/// outside of `DAYS`.
+pub fn report(day: u8, input: &str, params: &[Param]) -> Result<Option<String>, RunError> {
+    dispatch!(day, report_with(input, params))
+}
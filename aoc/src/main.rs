@@ -0,0 +1,526 @@
+mod all;
+mod bench;
+mod days;
+mod verify;
+
+use aoc_common::render::{self, Sink};
+use aoc_common::{log, Answer, InputSource, Part};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use serde_json::json;
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+use std::thread;
+use std::time::Instant;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print diagnostics to stderr. Repeat for more detail.
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or all of them.
+    Run {
+        /// Day number (1-25) or `all`.
+        day: DaySelection,
+        /// Only solve this part.
+        #[arg(long)]
+        part: Option<Part>,
+        /// Input file, `-` for stdin. Defaults to `input/day_XX.txt`.
+        input: Option<String>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Override a puzzle parameter, like `--param row=10` for the day 15 example.
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<days::Param>,
+        /// Read the input a line at a time instead of all at once, on the days
+        /// that support it.
+        #[arg(long)]
+        stream: bool,
+        /// After the answers, print a breakdown of the input behind them, on
+        /// the days that have one.
+        #[arg(long, conflicts_with = "stream")]
+        report: bool,
+        /// Record the simulation to `term`, or to a `.png` still of the last
+        /// frame, or an animated `.apng` or `.gif`.
+        #[arg(long, value_name = "TARGET")]
+        render: Option<RenderTarget>,
+        /// Only keep every Nth frame of the recording.
+        #[arg(long, default_value_t = 1, requires = "render")]
+        every: usize,
+        /// Pixels per cell in images.
+        #[arg(long, default_value_t = 4, requires = "render")]
+        scale: usize,
+        /// Milliseconds per frame in animations.
+        #[arg(long, default_value_t = 100, requires = "render")]
+        delay: u16,
+    },
+    /// Solve every part of every day at once, with a live progress table.
+    All {
+        /// Number of threads. Defaults to one per CPU.
+        #[arg(long)]
+        jobs: Option<usize>,
+    },
+    /// Print a random input for a day, for stress tests.
+    Gen {
+        /// Day number (1-25).
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Rough size of the input, see the README for what it means per day.
+        #[arg(long)]
+        size: usize,
+        /// Override a puzzle parameter the generator should respect.
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<days::Param>,
+    },
+    /// Check every solver against the known answers, using the default inputs.
+    Verify {
+        /// Day number (1-25) or `all`.
+        #[arg(default_value = "all")]
+        day: DaySelection,
+        /// File with the known answers.
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Time parsing and both parts of every day, using the default inputs.
+    Bench {
+        /// Day number (1-25) or `all`.
+        #[arg(default_value = "all")]
+        day: DaySelection,
+        /// Run every day this many times and keep the fastest timings.
+        #[arg(long, default_value_t = 1)]
+        runs: usize,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Earlier `--format json` output to flag regressions against.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// How many percent slower than the baseline counts as a regression.
+        #[arg(long, default_value_t = 20)]
+        threshold: u64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone)]
+enum RenderTarget {
+    Terminal,
+    Png(PathBuf),
+    Apng(PathBuf),
+    Gif(PathBuf),
+}
+
+impl FromStr for RenderTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let path = PathBuf::from(s);
+        match path.extension().and_then(|e| e.to_str()) {
+            _ if s == "term" => Ok(RenderTarget::Terminal),
+            Some("png") => Ok(RenderTarget::Png(path)),
+            Some("apng") => Ok(RenderTarget::Apng(path)),
+            Some("gif") => Ok(RenderTarget::Gif(path)),
+            _ => Err(format!(
+                "Invalid render target {}, expected `term` or a .png, .apng or .gif file",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl DaySelection {
+    fn days(self) -> Vec<u8> {
+        match self {
+            DaySelection::All => days::DAYS.collect(),
+            DaySelection::Day(d) => vec![d],
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+        match s.parse::<u8>() {
+            Ok(d) if days::DAYS.contains(&d) => Ok(DaySelection::Day(d)),
+            _ => Err(format!("Invalid day {}, expected 1-25 or `all`", s)),
+        }
+    }
+}
+
+fn parse_param(s: &str) -> Result<days::Param, String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+        _ => Err(format!("Invalid parameter {}, expected NAME=VALUE", s)),
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    log::set_verbosity(cli.verbose);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            params,
+            stream,
+            report,
+            render: render_target,
+            every,
+            scale,
+            delay,
+        } => {
+            if matches!(day, DaySelection::All)
+                && (input.is_some() || !params.is_empty() || render_target.is_some())
+            {
+                eprintln!(
+                    "An input, parameters or rendering can only be given when running a single day"
+                );
+                process::exit(2);
+            }
+            if let Some(target) = &render_target {
+                render::start_recording(every, render_sink(target, scale.max(1), delay));
+            }
+            let parts = match part {
+                Some(p) => vec![p],
+                None => Part::ALL.to_vec(),
+            };
+
+            for d in day.days() {
+                let source = InputSource::from_arg(input.as_deref(), d);
+                let result = match stream {
+                    true => stream_day(d, &source, &parts, &params),
+                    false => Ok(None),
+                };
+                let result = match result {
+                    Ok(Some(result)) => Ok((result, None)),
+                    Ok(None) => match source.read() {
+                        Ok(inp) => {
+                            days::run(d, &inp, &parts, &params).and_then(|res| match report {
+                                true => Ok((res, days::report(d, &inp, &params)?)),
+                                false => Ok((res, None)),
+                            })
+                        }
+                        Err(e) => {
+                            eprintln!("{}", e);
+                            process::exit(1);
+                        }
+                    },
+                    Err(e) => Err(e),
+                };
+                let (result, day_report) = match result {
+                    Ok(result) => result,
+                    Err(days::RunError::Param(e)) => {
+                        eprintln!("Day {:02}: {}", d, e);
+                        process::exit(2);
+                    }
+                    Err(days::RunError::Parse(e)) => {
+                        eprintln!("Failed to parse {}: {}", source, e);
+                        process::exit(1);
+                    }
+                    Err(days::RunError::Read(e)) => {
+                        eprintln!("Failed to read {}: {}", source, e);
+                        process::exit(1);
+                    }
+                };
+                // Streamed days parse as they go, there's no separate time for it.
+                if !result.parse_time.is_zero() {
+                    aoc_common::debug!("Day {:02}: parsed in {:?}", d, result.parse_time);
+                }
+                if render_target.is_some() {
+                    if let Err(e) = render::stop_recording() {
+                        eprintln!("{}", e);
+                        process::exit(1);
+                    }
+                }
+                for res in result.parts {
+                    match (format, &res.answer) {
+                        (Format::Json, _) => print_json(d, &res),
+                        (Format::Text, Some(answer)) => {
+                            print_answer(d, res.part, &answer.to_string())
+                        }
+                        (Format::Text, None) => {
+                            println!("Day {:02}, part {}: unsolved", d, res.part)
+                        }
+                    }
+                }
+                match day_report {
+                    Some(r) => print!("{}", r),
+                    None if report => aoc_common::debug!("Day {:02} has no report", d),
+                    None => {}
+                }
+            }
+        }
+        Command::All { jobs } => {
+            let threads =
+                jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+            if !run_all(threads.max(1)) {
+                process::exit(1);
+            }
+        }
+        Command::Gen {
+            day,
+            seed,
+            size,
+            params,
+        } => match days::generate(day, seed, size, &params) {
+            Ok(Some(input)) => print!("{}", input),
+            Ok(None) => {
+                eprintln!("Day {:02} has no input generator", day);
+                process::exit(2);
+            }
+            Err(e) => {
+                eprintln!("Day {:02}: {}", day, e);
+                process::exit(2);
+            }
+        },
+        Command::Verify { day, answers } => {
+            let answers = match verify::Answers::load(&answers) {
+                Ok(a) => a,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    process::exit(1);
+                }
+            };
+            if !run_verify(day, &answers) {
+                process::exit(1);
+            }
+        }
+        Command::Bench {
+            day,
+            runs,
+            format,
+            baseline,
+            threshold,
+        } => {
+            let mut report = run_bench(day, runs.max(1));
+            if let Some(path) = baseline {
+                match bench::Report::load(&path) {
+                    Ok(base) => report.compare(&base, threshold),
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        process::exit(1);
+                    }
+                }
+            }
+            match format {
+                Format::Text => print!("{}", report.to_table()),
+                Format::Json => println!("{}", report.to_json()),
+            }
+            if report.has_regressions() {
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Streams the day's input, or returns `None` if the day can only solve it
+/// from memory.
+fn stream_day(
+    day: u8,
+    source: &InputSource,
+    parts: &[Part],
+    params: &[days::Param],
+) -> Result<Option<days::DayResult>, days::RunError> {
+    let mut reader = match source.open() {
+        Ok(reader) => reader,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    let res = days::stream(day, &mut reader, parts, params)?;
+    if res.is_none() {
+        aoc_common::debug!("Day {:02} can't stream its input, reading it all", day);
+    }
+    Ok(res)
+}
+
+/// Returns whether no part failed. Parts without an input are skipped.
+fn run_all(threads: usize) -> bool {
+    let mut inputs = Vec::new();
+    let mut jobs = Vec::new();
+    for d in days::DAYS {
+        let input = InputSource::from_arg(None, d).read();
+        for part in Part::ALL {
+            let status = match &input {
+                Ok(_) => all::Status::Queued,
+                Err(e) => all::Status::Skipped {
+                    reason: e.to_string(),
+                },
+            };
+            jobs.push(all::Job {
+                day: d,
+                part,
+                status,
+            });
+        }
+        inputs.push(input.ok());
+    }
+
+    let solve = |day: u8, part| {
+        let inp = inputs[day as usize - 1].as_deref().unwrap_or_default();
+        match days::run(day, inp, &[part], &[]) {
+            Ok(mut result) => Ok(result.parts.pop().and_then(|r| r.answer)),
+            Err(e) => Err(format!("failed to parse input: {}", e)),
+        }
+    };
+
+    // The progress table is redrawn in place, which only makes sense on a
+    // terminal.
+    let live = io::stderr().is_terminal();
+    let mut drawn = 0;
+    let start = Instant::now();
+    all::run(&mut jobs, threads, solve, |jobs| {
+        if !live {
+            return;
+        }
+        let table = all::progress(jobs, Instant::now());
+        let mut stderr = io::stderr().lock();
+        if drawn > 0 {
+            let _ = write!(stderr, "\x1b[{}F\x1b[J", drawn);
+        }
+        let _ = write!(stderr, "{}", table);
+        drawn = table.lines().count();
+    });
+    if drawn > 0 {
+        eprint!("\x1b[{}F\x1b[J", drawn);
+    }
+
+    print!("{}", all::summary(&jobs, start.elapsed()));
+    !jobs
+        .iter()
+        .any(|j| matches!(j.status, all::Status::Failed { .. }))
+}
+
+fn run_bench(day: DaySelection, runs: usize) -> bench::Report {
+    let mut report = bench::Report::default();
+
+    for d in day.days() {
+        let inp = match InputSource::from_arg(None, d).read() {
+            Ok(inp) => inp,
+            Err(e) => {
+                eprintln!("Skipping day {:02}: {}", d, e);
+                continue;
+            }
+        };
+        let results: Result<Vec<_>, _> = (0..runs)
+            .map(|_| days::run(d, &inp, &Part::ALL, &[]))
+            .collect();
+        match results {
+            Ok(results) => report.timings.push(bench::Timing::from_runs(d, &results)),
+            Err(e) => eprintln!("Skipping day {:02}: failed to parse input: {}", d, e),
+        }
+    }
+    report
+}
+
+/// Returns whether all known answers were reproduced.
+fn run_verify(day: DaySelection, answers: &verify::Answers) -> bool {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for d in day.days() {
+        let inp = match InputSource::from_arg(None, d).read() {
+            Ok(inp) => inp,
+            Err(e) => {
+                // Without an input or answers, there's nothing to verify.
+                if answers.has_day(d) {
+                    println!("Day {:02}: FAIL ({})", d, e);
+                    failed += 1;
+                }
+                continue;
+            }
+        };
+
+        let result = match days::run(d, &inp, &Part::ALL, &[]) {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Day {:02}: FAIL (failed to parse input: {})", d, e);
+                failed += 1;
+                continue;
+            }
+        };
+        for res in result.parts {
+            let expected = answers.expected(d, res.part);
+            // Parts without a solution have nothing to check.
+            if expected.is_none() && res.answer.is_none() {
+                continue;
+            }
+            let verdict = verify::Verdict::check(expected, res.answer.as_ref());
+            match verdict {
+                verify::Verdict::Pass => passed += 1,
+                verify::Verdict::Fail { .. } => failed += 1,
+                verify::Verdict::Missing { .. } => missing += 1,
+            }
+            println!("Day {:02}, part {}: {}", d, res.part, verdict);
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    failed == 0
+}
+
+/// Where the recorded frames go, as they're recorded.
+fn render_sink(target: &RenderTarget, scale: usize, delay_ms: u16) -> Box<dyn Sink> {
+    match target {
+        RenderTarget::Terminal => Box::new(render::Terminal { delay_ms }),
+        RenderTarget::Png(path) => Box::new(render::PngStill::new(path, scale)),
+        RenderTarget::Apng(path) => Box::new(render::ApngWriter::new(path, scale, delay_ms)),
+        RenderTarget::Gif(path) => Box::new(render::GifWriter::new(path, scale, delay_ms)),
+    }
+}
+
+fn print_answer(day: u8, part: Part, answer: &str) {
+    // Multi-line answers (like CRT screens) start on their own line.
+    if answer.contains('\n') {
+        println!("Day {:02}, part {}:\n{}", day, part, answer);
+    } else {
+        println!("Day {:02}, part {}: {}", day, part, answer);
+    }
+}
+
+/// One JSON object per line, with the elapsed time in seconds.
+fn print_json(day: u8, res: &days::PartResult) {
+    let answer = match &res.answer {
+        Some(Answer::Int(x)) => json!(x),
+        Some(Answer::UInt(x)) => json!(x),
+        Some(Answer::Text(x)) => json!(x),
+        None => json!(null),
+    };
+    let record = json!({
+        "day": day,
+        "part": match res.part {
+            Part::One => 1,
+            Part::Two => 2,
+        },
+        "answer": answer,
+        "elapsed": res.elapsed.as_secs_f64(),
+    });
+    println!("{}", record);
+}
@@ -0,0 +1,169 @@
+use aoc_common::{Answer, Part};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Known-correct answers, read from a TOML file like:
+///
+/// ```toml
+/// [day_01]
+/// part_1 = 24000
+/// part_2 = 45000
+///
+/// [day_05]
+/// part_1 = "CMZ"
+/// ```
+pub struct Answers {
+    table: toml::Table,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let contents = fs::read_to_string(path).map_err(|e| AnswersError::Io {
+            path: path.to_path_buf(),
+            source: e,
+        })?;
+        let table = contents
+            .parse::<toml::Table>()
+            .map_err(|e| AnswersError::Toml {
+                path: path.to_path_buf(),
+                source: e,
+            })?;
+        Ok(Answers { table })
+    }
+
+    /// The expected answer, formatted the same way the solvers print theirs.
+    pub fn expected(&self, day: u8, part: Part) -> Option<String> {
+        let val = self
+            .table
+            .get(&format!("day_{:02}", day))?
+            .get(format!("part_{}", part))?;
+        match val {
+            toml::Value::String(s) => Some(s.trim_end().to_string()),
+            other => Some(other.to_string()),
+        }
+    }
+
+    pub fn has_day(&self, day: u8) -> bool {
+        self.table.contains_key(&format!("day_{:02}", day))
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Toml {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Io { path, source } => {
+                write!(f, "Failed to read {}: {}", path.display(), source)
+            }
+            AnswersError::Toml { path, source } => {
+                write!(f, "Failed to parse {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AnswersError::Io { source, .. } => Some(source),
+            AnswersError::Toml { source, .. } => Some(source),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        got: Option<String>,
+    },
+    Missing {
+        got: Option<String>,
+    },
+}
+
+impl Verdict {
+    pub fn check(expected: Option<String>, got: Option<&Answer>) -> Self {
+        let got = got.map(|a| a.to_string());
+        match expected {
+            None => Verdict::Missing { got },
+            Some(expected) if got.as_ref() == Some(&expected) => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected, got },
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail {
+                expected,
+                got: Some(got),
+            } => write!(f, "FAIL (expected {:?}, got {:?})", expected, got),
+            Verdict::Fail {
+                expected,
+                got: None,
+            } => write!(f, "FAIL (expected {:?}, got nothing)", expected),
+            Verdict::Missing { got: Some(got) } => write!(f, "MISSING (got {:?})", got),
+            Verdict::Missing { got: None } => write!(f, "MISSING"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(toml: &str) -> Answers {
+        Answers {
+            table: toml.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_expected() {
+        let a = answers("[day_01]\npart_1 = 24000\n\n[day_05]\npart_2 = \"MCD\"\n");
+        assert_eq!(a.expected(1, Part::One), Some("24000".to_string()));
+        assert_eq!(a.expected(1, Part::Two), None);
+        assert_eq!(a.expected(5, Part::Two), Some("MCD".to_string()));
+        assert_eq!(a.expected(2, Part::One), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let got = Answer::UInt(24000);
+        assert_eq!(
+            Verdict::check(Some("24000".to_string()), Some(&got)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            Verdict::check(Some("24001".to_string()), Some(&got)),
+            Verdict::Fail {
+                expected: "24001".to_string(),
+                got: Some("24000".to_string())
+            }
+        );
+        assert_eq!(
+            Verdict::check(None, Some(&got)),
+            Verdict::Missing {
+                got: Some("24000".to_string())
+            }
+        );
+    }
+}
@@ -0,0 +1,361 @@
+use crate::{parse, ParseError};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Position in a `Grid`, as (x, y) with y growing downwards.
+pub type Pos = (usize, usize);
+
+/// Position in a `SparseGrid`, which has no bounds.
+pub type SparsePos = (i64, i64);
+
+// Clockwise, starting top left.
+const OFFSETS_8: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+// Clockwise, starting at the top.
+const OFFSETS_4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// A rectangular grid, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Panics if the rows aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, |r| r.len());
+        let height = rows.len();
+        assert!(
+            rows.iter().all(|r| r.len() == width),
+            "All rows of a grid need the same length"
+        );
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses one cell per character, see `parse::grid`.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        f: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        parse::grid(input, expected, f).map(Grid::from_rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        match self.contains(pos) {
+            true => Some(&self.cells[pos.1 * self.width + pos.0]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        match self.contains(pos) {
+            true => Some(&mut self.cells[pos.1 * self.width + pos.0]),
+            false => None,
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "Column {} is out of bounds", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// All cells, row by row.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// All cells with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, c)| ((i % width, i / width), c))
+    }
+
+    pub fn position(&self, f: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, c)| f(c)).map(|(pos, _)| pos)
+    }
+
+    /// The horizontal and vertical neighbors of `pos` within the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offset_all(pos, &OFFSETS_4)
+    }
+
+    /// Like `neighbors4`, but including the diagonals.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offset_all(pos, &OFFSETS_8)
+    }
+
+    fn offset_all<'a>(
+        &'a self,
+        (x, y): Pos,
+        offsets: &'a [(i64, i64)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let pos = (
+                x.checked_add_signed(*dx as isize)?,
+                y.checked_add_signed(*dy as isize)?,
+            );
+            self.contains(pos).then_some(pos)
+        })
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// Draws the grid with one character per cell.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        let mut res = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            res.extend(row.iter().map(&f));
+            res.push('\n');
+        }
+        res
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(|c| *c))
+    }
+}
+
+/// The horizontal and vertical neighbors of `pos`, clockwise from the top.
+pub fn neighbors4((x, y): SparsePos) -> [SparsePos; 4] {
+    OFFSETS_4.map(|(dx, dy)| (x + dx, y + dy))
+}
+
+/// All 8 neighbors of `pos`, clockwise from the top left.
+pub fn neighbors8((x, y): SparsePos) -> [SparsePos; 8] {
+    OFFSETS_8.map(|(dx, dy)| (x + dx, y + dy))
+}
+
+/// A grid without bounds, that only stores the cells that were set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<SparsePos, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: SparsePos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: SparsePos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn insert(&mut self, pos: SparsePos, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: SparsePos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// The set cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (SparsePos, &T)> {
+        self.cells.iter().map(|(pos, c)| (*pos, c))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = SparsePos> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// The top left and bottom right corner of the set cells.
+    pub fn bounds(&self) -> Option<(SparsePos, SparsePos)> {
+        let min_x = self.positions().map(|(x, _)| x).min()?;
+        let max_x = self.positions().map(|(x, _)| x).max()?;
+        let min_y = self.positions().map(|(_, y)| y).min()?;
+        let max_y = self.positions().map(|(_, y)| y).max()?;
+        Some(((min_x, min_y), (max_x, max_y)))
+    }
+
+    /// Draws the bounds of the set cells, with `empty` for the unset ones.
+    pub fn render(&self, empty: char, f: impl Fn(&T) -> char) -> String {
+        let Some(((min_x, min_y), (max_x, max_y))) = self.bounds() else {
+            return String::new();
+        };
+        let mut res = String::new();
+        for y in min_y..=max_y {
+            res.extend((min_x..=max_x).map(|x| self.get((x, y)).map_or(empty, &f)));
+            res.push('\n');
+        }
+        res
+    }
+}
+
+impl<T> FromIterator<(SparsePos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (SparsePos, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("abc\ndef\n", "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).rev().collect::<String>(), "eb");
+        assert_eq!(grid.position(|c| *c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = example();
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbors8((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 0), (1, 0), (2, 0), (2, 1), (0, 1)]
+        );
+        assert_eq!(neighbors4((0, 0)), [(0, -1), (1, 0), (0, 1), (-1, 0)]);
+    }
+
+    #[test]
+    fn test_transpose_and_render() {
+        let grid = example().transpose();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid.to_string(), "ad\nbe\ncf\n");
+        assert_eq!(
+            grid.map(|c| *c == 'e').render(|b| ['.', '#'][*b as usize]),
+            "..\n.#\n..\n"
+        );
+    }
+
+    #[test]
+    fn test_sparse() {
+        let mut grid: SparseGrid<()> = [((-1, 2), ()), ((1, 0), ())].into_iter().collect();
+        assert_eq!(grid.bounds(), Some(((-1, 0), (1, 2))));
+        assert_eq!(grid.render('.', |_| '#'), "..#\n...\n#..\n");
+
+        grid.remove((-1, 2));
+        assert!(!grid.contains((-1, 2)));
+        assert_eq!(grid.render('.', |_| '#'), "#\n");
+        grid.remove((1, 0));
+        assert_eq!(grid.bounds(), None);
+    }
+}
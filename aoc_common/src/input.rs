@@ -0,0 +1,121 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command line argument: `-` means stdin, anything else is
+    /// a path. Without an argument, we fall back to `input/day_XX.txt`.
+    pub fn from_arg(arg: Option<&str>, day: u8) -> Self {
+        match arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::File(default_path(day)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path).map_err(|e| InputError::File {
+                path: path.clone(),
+                source: e,
+            }),
+            InputSource::Stdin => {
+                let mut buf = String::new();
+                io::stdin()
+                    .read_to_string(&mut buf)
+                    .map_err(InputError::Stdin)?;
+                Ok(buf)
+            }
+        }
+    }
+
+    /// Opens the input for reading it bit by bit, instead of all at once.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            InputSource::File(path) => match fs::File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(e) => Err(InputError::File {
+                    path: path.clone(),
+                    source: e,
+                }),
+            },
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    File { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::File { path, source } => {
+                write!(f, "Failed to read {}: {}", path.display(), source)
+            }
+            InputError::Stdin(source) => write!(f, "Failed to read stdin: {}", source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::File { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+/// The conventional location of a day's input, relative to the working directory.
+pub fn default_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("input/day_{:02}.txt", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg(Some("-"), 3), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Some("foo.txt"), 3),
+            InputSource::File(PathBuf::from("foo.txt"))
+        );
+        assert_eq!(
+            InputSource::from_arg(None, 3),
+            InputSource::File(PathBuf::from("input/day_03.txt"))
+        );
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let src = InputSource::File(PathBuf::from("does/not/exist.txt"));
+        let err = src.read().unwrap_err();
+        assert!(matches!(err, InputError::File { .. }));
+        assert!(err
+            .to_string()
+            .starts_with("Failed to read does/not/exist.txt"));
+    }
+}
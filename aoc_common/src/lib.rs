@@ -0,0 +1,14 @@
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod parse;
+pub mod render;
+pub mod search;
+pub mod solver;
+pub mod stream;
+
+pub use grid::{Grid, SparseGrid};
+pub use input::{InputError, InputSource};
+pub use parse::ParseError;
+pub use solver::{parse_param, Answer, ParamError, Part, Solver};
+pub use stream::StreamError;
@@ -0,0 +1,33 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// Diagnostics are off unless asked for, so stdout only carries answers.
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+/// Prints a diagnostic to stderr when running with `-v` or more.
+/// The arguments are only evaluated when the message will actually show.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::verbosity() >= 1 {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Like `debug!`, but for the really noisy stuff that needs `-vv`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::verbosity() >= 2 {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,416 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::vec;
+
+/// Where and why an input failed to parse. Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// An input that stopped before we found what we `expected`.
+    pub fn at_end(input: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(input.lines().count() + 1, 1, expected, "end of input")
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A cursor over a single line of input that remembers where it is, so every
+/// error can point at the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scanner<'a> {
+    line: usize,
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line: usize, text: &'a str) -> Self {
+        Scanner { line, text, pos: 0 }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    /// The whole line, including anything already consumed.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// An error at the current position, quoting whatever token is here.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let found = match self.peek() {
+            None => "end of line".to_string(),
+            Some(c) if c.is_whitespace() => format!("{:?}", c),
+            Some(_) => {
+                let token: String = self
+                    .rest()
+                    .chars()
+                    .take_while(|c| !c.is_whitespace())
+                    .take(20)
+                    .collect();
+                format!("`{}`", token)
+            }
+        };
+        ParseError::new(self.line, self.column(), expected, found)
+    }
+
+    /// Consumes `tag` if the line continues with it.
+    pub fn try_tag(&mut self, tag: &str) -> bool {
+        match self.rest().starts_with(tag) {
+            true => {
+                self.pos += tag.len();
+                true
+            }
+            false => false,
+        }
+    }
+
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        match self.try_tag(tag) {
+            true => Ok(()),
+            false => Err(self.error(format!("`{}`", tag))),
+        }
+    }
+
+    /// Consumes the first of `tags` the line continues with.
+    pub fn any_tag(&mut self, tags: &[&'static str]) -> Result<&'static str, ParseError> {
+        match tags.iter().find(|t| self.try_tag(t)) {
+            Some(t) => Ok(t),
+            None => Err(self.error(quote_options(tags.iter()))),
+        }
+    }
+
+    /// Consumes a single character, if `f` accepts it.
+    pub fn symbol<T>(
+        &mut self,
+        expected: &str,
+        f: impl FnOnce(char) -> Option<T>,
+    ) -> Result<T, ParseError> {
+        match self.peek().and_then(f) {
+            Some(res) => {
+                self.pos += self.peek().unwrap().len_utf8();
+                Ok(res)
+            }
+            None => Err(self.error(expected)),
+        }
+    }
+
+    /// Consumes one of the given characters.
+    pub fn one_of(&mut self, chars: &str) -> Result<char, ParseError> {
+        let expected = quote_options(chars.chars());
+        self.symbol(&expected, |c| chars.contains(c).then_some(c))
+    }
+
+    /// Consumes an (optionally negative) integer.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with('-'));
+        let len = sign
+            + rest[sign..]
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+        match rest[..len].parse() {
+            Ok(n) if len > sign => {
+                self.pos += len;
+                Ok(n)
+            }
+            _ => Err(self.error("a number")),
+        }
+    }
+
+    /// Consumes a run of letters and digits, like a name or a label.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        match self.take_while(|c| c.is_alphanumeric()) {
+            "" => Err(self.error("a name")),
+            word => Ok(word),
+        }
+    }
+
+    /// Consumes everything up to the next whitespace.
+    pub fn token(&mut self) -> Result<&'a str, ParseError> {
+        match self.take_while(|c| !c.is_whitespace()) {
+            "" => Err(self.error("a name")),
+            token => Ok(token),
+        }
+    }
+
+    pub fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    /// Parses a list of items separated by `sep`.
+    pub fn list<T>(
+        &mut self,
+        sep: &str,
+        mut f: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut res = vec![f(self)?];
+        while self.try_tag(sep) {
+            res.push(f(self)?);
+        }
+        Ok(res)
+    }
+
+    /// Fails if anything is left on the line.
+    pub fn end(&self) -> Result<(), ParseError> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self.error("end of line")),
+        }
+    }
+}
+
+fn quote_options<T: fmt::Display>(options: impl Iterator<Item = T>) -> String {
+    let options: Vec<String> = options.map(|o| format!("`{}`", o)).collect();
+    match options.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+        _ => options.concat(),
+    }
+}
+
+/// Numbered lines of an input, or of a block within it.
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    lines: vec::IntoIter<Scanner<'a>>,
+    // Where to report anything missing from the end, and what's there instead.
+    end_line: usize,
+    end: &'static str,
+}
+
+impl<'a> Lines<'a> {
+    pub fn new(input: &'a str) -> Self {
+        let lines: Vec<_> = input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| Scanner::new(i + 1, l))
+            .collect();
+        Lines {
+            end_line: lines.len() + 1,
+            lines: lines.into_iter(),
+            end: "end of input",
+        }
+    }
+
+    /// Splits an input into blocks of lines separated by blank lines.
+    pub fn blocks(input: &'a str) -> Vec<Self> {
+        let mut res = Vec::new();
+        let mut block = Vec::new();
+        for line in Lines::new(input) {
+            match line.text().trim().is_empty() {
+                true if block.is_empty() => {}
+                true => res.push(Lines {
+                    lines: std::mem::take(&mut block).into_iter(),
+                    end_line: line.line(),
+                    end: "a blank line",
+                }),
+                false => block.push(line),
+            }
+        }
+        if !block.is_empty() {
+            res.push(Lines {
+                end_line: block.last().unwrap().line() + 1,
+                lines: block.into_iter(),
+                end: "end of input",
+            });
+        }
+        res
+    }
+
+    /// The next line, which has to be there.
+    pub fn expect(&mut self, expected: &str) -> Result<Scanner<'a>, ParseError> {
+        self.lines
+            .next()
+            .ok_or_else(|| ParseError::new(self.end_line, 1, expected, self.end))
+    }
+
+    /// Fails if there are any lines left.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        match self.lines.next() {
+            None => Ok(()),
+            Some(line) => Err(line.error(self.end)),
+        }
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Scanner<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lines.next()
+    }
+}
+
+/// Parses a rectangular grid of characters, mapping each with `f`.
+pub fn grid<T>(
+    input: &str,
+    expected: &str,
+    f: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut res: Vec<Vec<T>> = Vec::new();
+    for mut line in Lines::new(input) {
+        let width = res.first().map(|r| r.len());
+        let mut row = Vec::with_capacity(width.unwrap_or(0));
+        while !line.is_empty() && Some(row.len()) != width {
+            row.push(line.symbol(expected, &f)?);
+        }
+        match width {
+            Some(w) if row.len() < w => return Err(line.error(expected)),
+            _ => line.end()?,
+        }
+        res.push(row);
+    }
+    if res.is_empty() || res[0].is_empty() {
+        return Err(ParseError::at_end(input, expected));
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner() {
+        let mut s = Scanner::new(3, "move 12 from -4 to x");
+        s.tag("move ").unwrap();
+        assert_eq!(s.number::<u32>(), Ok(12));
+        s.tag(" from ").unwrap();
+        assert_eq!(s.number::<i32>(), Ok(-4));
+        assert_eq!(s.column(), 16);
+        s.tag(" to ").unwrap();
+        assert_eq!(
+            s.number::<u32>(),
+            Err(ParseError::new(3, 20, "a number", "`x`"))
+        );
+        assert_eq!(s.one_of("xy"), Ok('x'));
+        s.end().unwrap();
+        assert_eq!(
+            s.error("more").to_string(),
+            "line 3, column 21: expected more, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_scanner_options() {
+        let mut s = Scanner::new(1, "B; tunnels");
+        assert_eq!(
+            s.one_of("AC"),
+            Err(ParseError::new(1, 1, "`A` or `C`", "`B;`"))
+        );
+        assert_eq!(s.word(), Ok("B"));
+        assert_eq!(
+            s.any_tag(&["; tunnel ", "; valve "]),
+            Err(ParseError::new(1, 2, "`; tunnel ` or `; valve `", "`;`"))
+        );
+        assert_eq!(s.any_tag(&["; valve ", "; tunnels"]), Ok("; tunnels"));
+    }
+
+    #[test]
+    fn test_list() {
+        let mut s = Scanner::new(1, "1, 2, 3");
+        assert_eq!(s.list(", ", |s| s.number::<u8>()), Ok(vec![1, 2, 3]));
+        let mut s = Scanner::new(1, "1, 2, ");
+        assert_eq!(
+            s.list(", ", |s| s.number::<u8>()),
+            Err(ParseError::new(1, 7, "a number", "end of line"))
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let mut blocks = Lines::blocks("a\nb\n\n\nc\n");
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].expect("a").unwrap().text(), "a");
+        assert_eq!(blocks[0].expect("b").unwrap().line(), 2);
+        assert_eq!(
+            blocks[0].expect("c"),
+            Err(ParseError::new(3, 1, "c", "a blank line"))
+        );
+        assert_eq!(blocks[1].expect("c").unwrap().line(), 5);
+        assert_eq!(
+            blocks[1].expect("d"),
+            Err(ParseError::new(6, 1, "d", "end of input"))
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let digit = |c: char| c.to_digit(10);
+        assert_eq!(
+            grid("12\n34\n", "a digit", digit),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+        assert_eq!(
+            grid("12\n3\n", "a digit", digit),
+            Err(ParseError::new(2, 2, "a digit", "end of line"))
+        );
+        assert_eq!(
+            grid("12\n345\n", "a digit", digit),
+            Err(ParseError::new(2, 3, "end of line", "`5`"))
+        );
+        assert_eq!(
+            grid("12\n3x\n", "a digit", digit),
+            Err(ParseError::new(2, 2, "a digit", "`x`"))
+        );
+        assert_eq!(
+            grid("", "a digit", digit),
+            Err(ParseError::new(1, 1, "a digit", "end of input"))
+        );
+    }
+}
@@ -0,0 +1,566 @@
+use crate::{Grid, SparseGrid};
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const BLACK: Color = Color(0, 0, 0);
+    pub const GREY: Color = Color(90, 90, 90);
+    pub const WHITE: Color = Color(230, 230, 230);
+    pub const RED: Color = Color(220, 50, 47);
+    pub const GREEN: Color = Color(80, 200, 80);
+    pub const BLUE: Color = Color(60, 120, 220);
+    pub const YELLOW: Color = Color(230, 200, 60);
+    pub const BROWN: Color = Color(140, 90, 50);
+}
+
+/// A character for the terminal, and the color it gets in both the terminal
+/// and images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub color: Color,
+}
+
+impl Cell {
+    pub const EMPTY: Cell = Cell::new(' ', Color::BLACK);
+
+    pub const fn new(ch: char, color: Color) -> Self {
+        Cell { ch, color }
+    }
+}
+
+/// A single picture of a simulation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+}
+
+impl Frame {
+    pub fn new(cells: Grid<Cell>) -> Self {
+        Frame { cells }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl Fn(&T) -> Cell) -> Self {
+        Frame::new(grid.map(f))
+    }
+
+    /// Draws the bounds of the set cells, with `empty` for the unset ones.
+    pub fn from_sparse<T>(grid: &SparseGrid<T>, empty: Cell, f: impl Fn(&T) -> Cell) -> Self {
+        let Some(((min_x, min_y), (max_x, max_y))) = grid.bounds() else {
+            return Frame::new(Grid::new(0, 0, empty));
+        };
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+        let mut cells = Grid::new(width, height, empty);
+        for ((x, y), value) in grid.iter() {
+            cells[((x - min_x) as usize, (y - min_y) as usize)] = f(value);
+        }
+        Frame::new(cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    /// The frame with 24-bit ANSI colors, one line per row.
+    pub fn to_ansi(&self) -> String {
+        let mut res = String::new();
+        for row in self.cells.rows() {
+            let mut color = None;
+            for cell in row {
+                if color != Some(cell.color) {
+                    let Color(r, g, b) = cell.color;
+                    res.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+                    color = Some(cell.color);
+                }
+                res.push(cell.ch);
+            }
+            res.push_str("\x1b[0m\n");
+        }
+        res
+    }
+
+    /// RGB pixels, with every cell a `scale` by `scale` block. The frame is
+    /// padded with black up to `width` by `height` cells.
+    fn pixels(&self, width: usize, height: usize, scale: usize) -> Vec<u8> {
+        let mut res = Vec::with_capacity(width * height * scale * scale * 3);
+        for y in 0..height * scale {
+            for x in 0..width * scale {
+                let Color(r, g, b) = self
+                    .cells
+                    .get((x / scale, y / scale))
+                    .map_or(Color::BLACK, |c| c.color);
+                res.extend([r, g, b]);
+            }
+        }
+        res
+    }
+}
+
+#[derive(Debug)]
+pub enum RenderError {
+    Io(io::Error),
+    Png(png::EncodingError),
+    Gif(gif::EncodingError),
+    NoFrames,
+    TooLarge,
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RenderError::Io(e) => write!(f, "Failed to write the image: {}", e),
+            RenderError::Png(e) => write!(f, "Failed to encode the PNG: {}", e),
+            RenderError::Gif(e) => write!(f, "Failed to encode the GIF: {}", e),
+            RenderError::NoFrames => write!(f, "Nothing was rendered"),
+            RenderError::TooLarge => write!(f, "The frames are too large for a GIF"),
+        }
+    }
+}
+
+impl Error for RenderError {}
+
+impl From<io::Error> for RenderError {
+    fn from(e: io::Error) -> Self {
+        RenderError::Io(e)
+    }
+}
+
+impl From<png::EncodingError> for RenderError {
+    fn from(e: png::EncodingError) -> Self {
+        RenderError::Png(e)
+    }
+}
+
+impl From<gif::EncodingError> for RenderError {
+    fn from(e: gif::EncodingError) -> Self {
+        RenderError::Gif(e)
+    }
+}
+
+fn png_encoder(
+    path: &Path,
+    (width, height): (usize, usize),
+    scale: usize,
+) -> Result<png::Encoder<'static, BufWriter<File>>, RenderError> {
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = png::Encoder::new(file, (width * scale) as u32, (height * scale) as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    Ok(encoder)
+}
+
+/// Writes a single frame as a PNG still.
+pub fn write_png(path: &Path, frame: &Frame, scale: usize) -> Result<(), RenderError> {
+    let size = (frame.width(), frame.height());
+    if size.0 == 0 || size.1 == 0 {
+        return Err(RenderError::NoFrames);
+    }
+    let mut writer = png_encoder(path, size, scale)?.write_header()?;
+    writer.write_image_data(&frame.pixels(size.0, size.1, scale))?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Where recorded frames go, one at a time as they're recorded.
+pub trait Sink: Send {
+    fn frame(&mut self, frame: &Frame) -> Result<(), RenderError>;
+
+    /// Called after the last frame.
+    fn finish(self: Box<Self>) -> Result<(), RenderError>;
+}
+
+/// Plays the frames on stderr as they come in, to keep stdout for the answers.
+pub struct Terminal {
+    pub delay_ms: u16,
+}
+
+impl Sink for Terminal {
+    fn frame(&mut self, frame: &Frame) -> Result<(), RenderError> {
+        eprint!("\x1b[2J\x1b[H{}", frame.to_ansi());
+        thread::sleep(Duration::from_millis(self.delay_ms.into()));
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), RenderError> {
+        Ok(())
+    }
+}
+
+/// Only keeps the last frame, for a PNG still.
+pub struct PngStill {
+    path: PathBuf,
+    scale: usize,
+    last: Option<Frame>,
+}
+
+impl PngStill {
+    pub fn new(path: &Path, scale: usize) -> Self {
+        PngStill {
+            path: path.to_path_buf(),
+            scale,
+            last: None,
+        }
+    }
+}
+
+impl Sink for PngStill {
+    fn frame(&mut self, frame: &Frame) -> Result<(), RenderError> {
+        self.last = Some(frame.clone());
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), RenderError> {
+        let last = self.last.ok_or(RenderError::NoFrames)?;
+        write_png(&self.path, &last, self.scale)
+    }
+}
+
+/// Encodes every frame into an animated GIF as it comes in. The GIF's size
+/// comes first in the file, so it's patched to the largest frame at the end.
+/// Smaller frames leave the rest of the image blank.
+pub struct GifWriter {
+    path: PathBuf,
+    scale: usize,
+    delay_ms: u16,
+    encoder: Option<gif::Encoder<BufWriter<File>>>,
+    size: (u16, u16),
+}
+
+impl GifWriter {
+    pub fn new(path: &Path, scale: usize, delay_ms: u16) -> Self {
+        GifWriter {
+            path: path.to_path_buf(),
+            scale,
+            delay_ms,
+            encoder: None,
+            size: (0, 0),
+        }
+    }
+}
+
+impl Sink for GifWriter {
+    fn frame(&mut self, frame: &Frame) -> Result<(), RenderError> {
+        let (w, h) = (
+            u16::try_from(frame.width() * self.scale).map_err(|_| RenderError::TooLarge)?,
+            u16::try_from(frame.height() * self.scale).map_err(|_| RenderError::TooLarge)?,
+        );
+        if w == 0 || h == 0 {
+            return Ok(());
+        }
+        let encoder = match &mut self.encoder {
+            Some(encoder) => encoder,
+            None => {
+                let file = BufWriter::new(File::create(&self.path)?);
+                let mut encoder = gif::Encoder::new(file, w, h, &[])?;
+                encoder.set_repeat(gif::Repeat::Infinite)?;
+                self.encoder.insert(encoder)
+            }
+        };
+        self.size = (self.size.0.max(w), self.size.1.max(h));
+
+        let pixels = frame.pixels(frame.width(), frame.height(), self.scale);
+        let mut gif_frame = gif::Frame::from_rgb_speed(w, h, &pixels, 10);
+        // GIF delays are in hundredths of a second.
+        gif_frame.delay = self.delay_ms / 10;
+        gif_frame.dispose = gif::DisposalMethod::Background;
+        encoder.write_frame(&gif_frame)?;
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), RenderError> {
+        let encoder = self.encoder.ok_or(RenderError::NoFrames)?;
+        let mut file = encoder
+            .into_inner()?
+            .into_inner()
+            .map_err(|e| e.into_error())?;
+        // The size comes right after the `GIF89a` signature.
+        file.seek(SeekFrom::Start(6))?;
+        file.write_all(&self.size.0.to_le_bytes())?;
+        file.write_all(&self.size.1.to_le_bytes())?;
+        Ok(())
+    }
+}
+
+/// Writes an animated PNG. That needs its size and number of frames before
+/// the first one, so the frames are spooled to a file next to it as they come
+/// in, and only encoded at the end.
+pub struct ApngWriter {
+    path: PathBuf,
+    scale: usize,
+    delay_ms: u16,
+    spool: Option<BufWriter<File>>,
+    frames: u32,
+    size: (usize, usize),
+}
+
+impl ApngWriter {
+    pub fn new(path: &Path, scale: usize, delay_ms: u16) -> Self {
+        ApngWriter {
+            path: path.to_path_buf(),
+            scale,
+            delay_ms,
+            spool: None,
+            frames: 0,
+            size: (0, 0),
+        }
+    }
+
+    fn spool_path(&self) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".frames");
+        path.into()
+    }
+
+    fn encode(&self) -> Result<(), RenderError> {
+        let (width, height) = self.size;
+        if width == 0 || height == 0 {
+            return Err(RenderError::NoFrames);
+        }
+        let mut encoder = png_encoder(&self.path, self.size, self.scale)?;
+        encoder.set_animated(self.frames, 0)?;
+        encoder.set_frame_delay(self.delay_ms, 1000)?;
+        let mut writer = encoder.write_header()?;
+        let mut spool = BufReader::new(File::open(self.spool_path())?);
+        for _ in 0..self.frames {
+            let frame = read_raw(&mut spool)?;
+            writer.write_image_data(&frame.pixels(width, height, self.scale))?;
+        }
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+impl Sink for ApngWriter {
+    fn frame(&mut self, frame: &Frame) -> Result<(), RenderError> {
+        let spool = match &mut self.spool {
+            Some(spool) => spool,
+            None => {
+                let file = File::create(self.spool_path())?;
+                self.spool.insert(BufWriter::new(file))
+            }
+        };
+        write_raw(frame, spool)?;
+        self.frames += 1;
+        self.size = (
+            self.size.0.max(frame.width()),
+            self.size.1.max(frame.height()),
+        );
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<(), RenderError> {
+        let Some(mut spool) = self.spool.take() else {
+            return Err(RenderError::NoFrames);
+        };
+        spool.flush()?;
+        drop(spool);
+        let res = self.encode();
+        fs::remove_file(self.spool_path())?;
+        res
+    }
+}
+
+/// A frame's size and the color of every cell, for spooling.
+fn write_raw(frame: &Frame, w: &mut impl Write) -> io::Result<()> {
+    w.write_all(&(frame.width() as u32).to_le_bytes())?;
+    w.write_all(&(frame.height() as u32).to_le_bytes())?;
+    for cell in frame.cells.values() {
+        let Color(r, g, b) = cell.color;
+        w.write_all(&[r, g, b])?;
+    }
+    Ok(())
+}
+
+fn read_raw(r: &mut impl Read) -> io::Result<Frame> {
+    let mut buf = [0; 4];
+    r.read_exact(&mut buf)?;
+    let width = u32::from_le_bytes(buf) as usize;
+    r.read_exact(&mut buf)?;
+    let height = u32::from_le_bytes(buf) as usize;
+    let mut cells = Grid::new(width, height, Cell::EMPTY);
+    for y in 0..height {
+        for cell in cells.row_mut(y) {
+            let mut rgb = [0; 3];
+            r.read_exact(&mut rgb)?;
+            cell.color = Color(rgb[0], rgb[1], rgb[2]);
+        }
+    }
+    Ok(Frame::new(cells))
+}
+
+// Recording is off unless asked for, like the log verbosity.
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static RECORDER: Mutex<Recorder> = Mutex::new(Recorder {
+    every: 1,
+    seen: 0,
+    sink: None,
+    error: None,
+});
+
+struct Recorder {
+    every: usize,
+    seen: usize,
+    sink: Option<Box<dyn Sink>>,
+    /// The first error a frame ran into, after which the rest are dropped.
+    error: Option<RenderError>,
+}
+
+/// Starts passing every `every`th frame given to `record` on to the `sink`.
+pub fn start_recording(every: usize, sink: Box<dyn Sink>) {
+    let mut recorder = RECORDER.lock().unwrap();
+    recorder.every = every.max(1);
+    recorder.seen = 0;
+    recorder.sink = Some(sink);
+    recorder.error = None;
+    RECORDING.store(true, Ordering::Relaxed);
+}
+
+/// Stops recording and finishes the sink, unless a frame failed before.
+pub fn stop_recording() -> Result<(), RenderError> {
+    RECORDING.store(false, Ordering::Relaxed);
+    let mut recorder = RECORDER.lock().unwrap();
+    let sink = recorder.sink.take();
+    if let Some(e) = recorder.error.take() {
+        return Err(e);
+    }
+    match sink {
+        Some(sink) => sink.finish(),
+        None => Ok(()),
+    }
+}
+
+pub fn is_recording() -> bool {
+    RECORDING.load(Ordering::Relaxed)
+}
+
+/// Passes a frame on to the recording, if any. The frame is only drawn when
+/// it will actually be kept.
+pub fn record(frame: impl FnOnce() -> Frame) {
+    if !is_recording() {
+        return;
+    }
+    let mut recorder = RECORDER.lock().unwrap();
+    let Recorder {
+        every,
+        seen,
+        sink,
+        error,
+    } = &mut *recorder;
+    if seen.is_multiple_of(*every) && error.is_none() {
+        if let Some(sink) = sink {
+            if let Err(e) = sink.frame(&frame()) {
+                *error = Some(e);
+            }
+        }
+    }
+    *seen += 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Frame {
+        let grid = Grid::parse("#.\n.#\n", "a wall or ground", Some).unwrap();
+        Frame::from_grid(&grid, |c| match c {
+            '#' => Cell::new('#', Color::WHITE),
+            _ => Cell::new('.', Color::GREY),
+        })
+    }
+
+    #[test]
+    fn test_to_ansi() {
+        assert_eq!(
+            example().to_ansi(),
+            "\x1b[38;2;230;230;230m#\x1b[38;2;90;90;90m.\x1b[0m\n\
+             \x1b[38;2;90;90;90m.\x1b[38;2;230;230;230m#\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_pixels() {
+        let frame = example();
+        // Scaled up to 4x4 pixels, and padded to 3x3 cells.
+        let pixels = frame.pixels(3, 3, 2);
+        assert_eq!(pixels.len(), 6 * 6 * 3);
+        assert_eq!(pixels[0..3], [230, 230, 230]);
+        assert_eq!(pixels[3 * 3..3 * 3 + 3], [90, 90, 90]);
+        assert_eq!(pixels[pixels.len() - 3..], [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_from_sparse() {
+        let grid: SparseGrid<()> = [((5, 5), ()), ((6, 7), ())].into_iter().collect();
+        let frame = Frame::from_sparse(&grid, Cell::EMPTY, |_| Cell::new('#', Color::RED));
+        assert_eq!((frame.width(), frame.height()), (2, 3));
+    }
+
+    fn write(mut sink: Box<dyn Sink>, frames: &[Frame]) -> Result<(), RenderError> {
+        for frame in frames {
+            sink.frame(frame)?;
+        }
+        sink.finish()
+    }
+
+    #[test]
+    fn test_write_images() {
+        let dir = std::env::temp_dir();
+        let small = Frame::new(Grid::new(1, 1, Cell::new('#', Color::RED)));
+        let frames = vec![small, example(), example()];
+        for name in [
+            "aoc_render_test.png",
+            "aoc_render_test.apng",
+            "aoc_render_test.gif",
+        ] {
+            let path = dir.join(name);
+            let sink: Box<dyn Sink> = match name.rsplit('.').next() {
+                Some("png") => Box::new(PngStill::new(&path, 2)),
+                Some("apng") => Box::new(ApngWriter::new(&path, 2, 100)),
+                _ => Box::new(GifWriter::new(&path, 2, 100)),
+            };
+            write(sink, &frames).unwrap();
+            assert!(std::fs::metadata(&path).unwrap().len() > 0);
+            std::fs::remove_file(path).unwrap();
+        }
+        // The spooled frames are gone.
+        assert!(!dir.join("aoc_render_test.apng.frames").exists());
+
+        // Sized after the largest frame, not the first.
+        let path = dir.join("aoc_render_size.gif");
+        write(Box::new(GifWriter::new(&path, 2, 100)), &frames).unwrap();
+        let gif = std::fs::read(&path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(gif[6..10], [4, 0, 4, 0]);
+
+        let path = dir.join("aoc_render_empty.gif");
+        assert!(matches!(
+            write(Box::new(GifWriter::new(&path, 2, 100)), &[]),
+            Err(RenderError::NoFrames)
+        ));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_spool() {
+        let frame = example();
+        let mut buf = Vec::new();
+        write_raw(&frame, &mut buf).unwrap();
+        let read = read_raw(&mut buf.as_slice()).unwrap();
+        assert_eq!(read.pixels(2, 2, 1), frame.pixels(2, 2, 1));
+    }
+}
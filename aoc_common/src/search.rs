@@ -0,0 +1,325 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cost of reaching a goal, and the nodes along the way, starting with
+/// the start node and ending with the goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Something to add up along a path. `C::default()` is the cost of not moving.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+// Nodes are numbered in the order they're found, so the rest of the search
+// only has to deal with indices.
+struct Seen<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    parent: Vec<Option<usize>>,
+}
+
+impl<N: Eq + Hash + Clone> Seen<N> {
+    fn new() -> Self {
+        Seen {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            parent: Vec::new(),
+        }
+    }
+
+    /// Returns the index of the node, and whether it's new.
+    fn insert(&mut self, node: N, parent: Option<usize>) -> (usize, bool) {
+        match self.index.entry(node) {
+            Entry::Occupied(e) => (*e.get(), false),
+            Entry::Vacant(e) => {
+                let idx = self.nodes.len();
+                self.nodes.push(e.key().clone());
+                self.parent.push(parent);
+                e.insert(idx);
+                (idx, true)
+            }
+        }
+    }
+
+    fn path<C>(&self, mut idx: usize, cost: C) -> Path<N, C> {
+        let mut nodes = vec![self.nodes[idx].clone()];
+        while let Some(parent) = self.parent[idx] {
+            nodes.push(self.nodes[parent].clone());
+            idx = parent;
+        }
+        nodes.reverse();
+        Path { cost, nodes }
+    }
+}
+
+/// Breadth-first search for the closest goal from any of the `starts`, where
+/// every step costs 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = Seen::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let (idx, true) = seen.insert(start, None) {
+            queue.push_back((idx, 0));
+        }
+    }
+
+    while let Some((idx, steps)) = queue.pop_front() {
+        if is_goal(&seen.nodes[idx]) {
+            return Some(seen.path(idx, steps));
+        }
+        for next in neighbors(&seen.nodes[idx]) {
+            if let (next_idx, true) = seen.insert(next, Some(idx)) {
+                queue.push_back((next_idx, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Every node that can be reached from the `starts`, including themselves.
+pub fn reachable<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> HashSet<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen: HashSet<N> = HashSet::new();
+    let mut queue: VecDeque<N> = starts.into_iter().collect();
+    while let Some(node) = queue.pop_front() {
+        if seen.insert(node.clone()) {
+            queue.extend(neighbors(&node).into_iter().filter(|n| !seen.contains(n)));
+        }
+    }
+    seen
+}
+
+/// Cheapest path from any of the `starts` to a goal, with `neighbors`
+/// returning each next node together with the cost of getting there.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// Like `dijkstra`, but guided by a `heuristic` that estimates the remaining
+/// cost to a goal. It must never overestimate, or the path may not be the
+/// cheapest.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut seen = Seen::new();
+    // Best known cost for each node, by index.
+    let mut best: Vec<C> = Vec::new();
+    // `Reverse` turns the max-heap into a min-heap, ordered by estimated total cost.
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let (idx, true) = seen.insert(start, None) {
+            best.push(C::default());
+            queue.push(Reverse((estimate, C::default(), idx)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, idx))) = queue.pop() {
+        // Do we already have a better path to this node?
+        if cost > best[idx] {
+            continue;
+        }
+        if is_goal(&seen.nodes[idx]) {
+            return Some(seen.path(idx, cost));
+        }
+
+        for (next, step) in neighbors(&seen.nodes[idx]) {
+            let new_cost = cost + step;
+            let estimate = heuristic(&next);
+            let (next_idx, is_new) = seen.insert(next, Some(idx));
+            if is_new {
+                best.push(new_cost);
+            } else if new_cost < best[next_idx] {
+                best[next_idx] = new_cost;
+                seen.parent[next_idx] = Some(idx);
+            } else {
+                continue;
+            }
+            queue.push(Reverse((new_cost + estimate, new_cost, next_idx)));
+        }
+    }
+    None
+}
+
+/// Shortest paths between all pairs of nodes `0..n`.
+#[derive(Debug, Clone)]
+pub struct AllPairs<C> {
+    cost: Vec<Vec<Option<C>>>,
+    // The node after `i` on the way from `i` to `j`.
+    next: Vec<Vec<Option<usize>>>,
+}
+
+impl<C: Cost> AllPairs<C> {
+    /// The cheapest cost from `from` to `to`, if there's a path at all.
+    pub fn cost(&self, from: usize, to: usize) -> Option<C> {
+        self.cost[from][to]
+    }
+
+    pub fn path(&self, from: usize, to: usize) -> Option<Path<usize, C>> {
+        let cost = self.cost[from][to]?;
+        let mut nodes = vec![from];
+        let mut at = from;
+        while at != to {
+            at = self.next[at][to]?;
+            nodes.push(at);
+        }
+        Some(Path { cost, nodes })
+    }
+}
+
+/// Floyd–Warshall over nodes `0..n`, with directed edges `(from, to, cost)`.
+pub fn floyd_warshall<C: Cost>(
+    n: usize,
+    edges: impl IntoIterator<Item = (usize, usize, C)>,
+) -> AllPairs<C> {
+    let mut cost = vec![vec![None; n]; n];
+    let mut next = vec![vec![None; n]; n];
+
+    // Distance to itself is 0
+    for i in 0..n {
+        cost[i][i] = Some(C::default());
+        next[i][i] = Some(i);
+    }
+    for (from, to, c) in edges {
+        if cost[from][to].is_none_or(|old| c < old) {
+            cost[from][to] = Some(c);
+            next[from][to] = Some(to);
+        }
+    }
+
+    // Find minimum distances for all pairs, going through `k`
+    for k in 0..n {
+        for i in 0..n {
+            let Some(to_k) = cost[i][k] else { continue };
+            for j in 0..n {
+                let Some(from_k) = cost[k][j] else { continue };
+                if cost[i][j].is_none_or(|old| to_k + from_k < old) {
+                    cost[i][j] = Some(to_k + from_k);
+                    next[i][j] = next[i][k];
+                }
+            }
+        }
+    }
+    AllPairs { cost, next }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 costs 3, 0 -> 3 directly costs 5, 4 is unreachable.
+    fn edges(node: &usize) -> Vec<(usize, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs([0], |n| edges(n).into_iter().map(|(n, _)| n), |n| *n == 3).unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.nodes, vec![0, 3]);
+
+        // The closest start wins.
+        let path = bfs(
+            [0, 1],
+            |n| edges(n).into_iter().map(|(n, _)| n),
+            |n| *n == 2,
+        )
+        .unwrap();
+        assert_eq!(path.nodes, vec![1, 2]);
+
+        assert_eq!(
+            bfs([0], |n| edges(n).into_iter().map(|(n, _)| n), |n| *n == 4),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reachable() {
+        let found = reachable([1], |n| edges(n).into_iter().map(|(n, _)| n));
+        assert_eq!(found, HashSet::from([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra([0], edges, |n| *n == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_astar() {
+        // Manhattan distance on an open 10x10 grid.
+        let goal = (9i32, 9i32);
+        let path = astar(
+            [(0i32, 0i32)],
+            |&(x, y)| {
+                [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)]
+                    .into_iter()
+                    .filter(|(x, y)| (0..10).contains(x) && (0..10).contains(y))
+                    .map(|p| (p, 1))
+            },
+            |&(x, y)| (goal.0 - x).abs() + (goal.1 - y).abs(),
+            |p| *p == goal,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 18);
+        assert_eq!(path.nodes.len(), 19);
+    }
+
+    #[test]
+    fn test_floyd_warshall() {
+        let all = floyd_warshall(
+            5,
+            (0..5).flat_map(|n| edges(&n).into_iter().map(move |(m, c)| (n, m, c))),
+        );
+        assert_eq!(all.cost(0, 3), Some(3));
+        assert_eq!(all.path(0, 3).unwrap().nodes, vec![0, 1, 2, 3]);
+        assert_eq!(all.cost(3, 0), None);
+        assert_eq!(all.path(0, 4), None);
+        assert_eq!(all.path(2, 2).unwrap().nodes, vec![2]);
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{ParseError, StreamError};
+use std::error::Error;
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+/// A puzzle answer. Most are numbers, but some days spell out their answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{}", x),
+            Answer::UInt(x) => write!(f, "{}", x),
+            Answer::Text(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(item: i64) -> Self {
+        Answer::Int(item)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(item: u64) -> Self {
+        Answer::UInt(item)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(item: u32) -> Self {
+        Answer::UInt(item as u64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(item: usize) -> Self {
+        Answer::UInt(item as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(item: String) -> Self {
+        Answer::Text(item)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            x => Err(format!("Invalid part {}, expected 1 or 2", x)),
+        }
+    }
+}
+
+/// A single day's puzzle. Parsing is split from solving, so both parts can
+/// share the parsed input.
+pub trait Solver {
+    type Input;
+
+    /// Tunable puzzle parameters and their current values, like the row to
+    /// check on day 15. The examples often need other values than the real input.
+    fn params(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<(), ParamError> {
+        Err(ParamError::unknown(name, self.params()))
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_1(&self, input: &Self::Input) -> Answer;
+
+    /// Not every day has (or has a solution for) a second part.
+    fn part_2(&self, input: &Self::Input) -> Option<Answer>;
+
+    /// A random but valid input, for stress tests. What `size` means differs
+    /// per day, like the number of valves on day 16. Most days have no
+    /// generator.
+    fn generate(&self, _seed: u64, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// Solves both parts straight from a reader in a single pass, without
+    /// holding the whole input in memory. Only some line-oriented days can.
+    fn stream(
+        &self,
+        _input: &mut dyn BufRead,
+    ) -> Option<Result<(Answer, Option<Answer>), StreamError>> {
+        None
+    }
+
+    /// A breakdown of the input behind the answers, for checking them by
+    /// hand. Most days have none.
+    fn report(&self, _input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    fn solve(&self, input: &Self::Input, part: Part) -> Option<Answer> {
+        match part {
+            Part::One => Some(self.part_1(input)),
+            Part::Two => self.part_2(input),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    Unknown {
+        name: String,
+        known: Vec<&'static str>,
+    },
+    Invalid {
+        name: String,
+        value: String,
+    },
+}
+
+impl ParamError {
+    pub fn unknown(name: &str, known: Vec<(&'static str, String)>) -> Self {
+        ParamError::Unknown {
+            name: name.to_string(),
+            known: known.into_iter().map(|(n, _)| n).collect(),
+        }
+    }
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamError::Unknown { name, known } if known.is_empty() => {
+                write!(f, "Unknown parameter {}, this day has none", name)
+            }
+            ParamError::Unknown { name, known } => {
+                write!(
+                    f,
+                    "Unknown parameter {}, expected one of: {}",
+                    name,
+                    known.join(", ")
+                )
+            }
+            ParamError::Invalid { name, value } => {
+                write!(f, "Invalid value {} for parameter {}", value, name)
+            }
+        }
+    }
+}
+
+impl Error for ParamError {}
+
+/// Parses the value of a parameter, for use in `Solver::set_param`.
+pub fn parse_param<T: FromStr>(name: &str, value: &str) -> Result<T, ParamError> {
+    value.parse().map_err(|_| ParamError::Invalid {
+        name: name.to_string(),
+        value: value.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Example {
+        rounds: usize,
+    }
+
+    impl Solver for Example {
+        type Input = ();
+
+        fn params(&self) -> Vec<(&'static str, String)> {
+            vec![("rounds", self.rounds.to_string())]
+        }
+
+        fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+            match name {
+                "rounds" => self.rounds = parse_param(name, value)?,
+                _ => return Err(ParamError::unknown(name, self.params())),
+            }
+            Ok(())
+        }
+
+        fn parse(&self, _input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part_1(&self, _input: &Self::Input) -> Answer {
+            self.rounds.into()
+        }
+
+        fn part_2(&self, _input: &Self::Input) -> Option<Answer> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_set_param() {
+        let mut solver = Example::default();
+        solver.set_param("rounds", "20").unwrap();
+        assert_eq!(solver.part_1(&()), Answer::UInt(20));
+
+        assert_eq!(
+            solver
+                .set_param("rounds", "twenty")
+                .unwrap_err()
+                .to_string(),
+            "Invalid value twenty for parameter rounds"
+        );
+        assert_eq!(
+            solver.set_param("minutes", "20").unwrap_err().to_string(),
+            "Unknown parameter minutes, expected one of: rounds"
+        );
+    }
+}
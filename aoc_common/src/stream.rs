@@ -0,0 +1,129 @@
+use crate::parse::Scanner;
+use crate::ParseError;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "{}", e),
+            StreamError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(e) => Some(e),
+            StreamError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        StreamError::Parse(e)
+    }
+}
+
+/// Calls `f` with every line of `reader`, numbered from 1 like `Lines`. Only
+/// one line is in memory at a time. Returns the number of lines, to report
+/// anything missing at the end.
+pub fn lines(
+    reader: &mut dyn BufRead,
+    mut f: impl FnMut(Scanner) -> Result<(), ParseError>,
+) -> Result<usize, StreamError> {
+    let mut buf = String::new();
+    let mut count = 0;
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            return Ok(count);
+        }
+        count += 1;
+        // Same line endings as `str::lines`.
+        let text = buf.strip_suffix('\n').unwrap_or(&buf);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        f(Scanner::new(count, text))?;
+    }
+}
+
+/// Calls `f` with every byte of `reader` and its position, counting from 0.
+/// Returns the number of bytes.
+pub fn bytes(
+    reader: &mut dyn BufRead,
+    mut f: impl FnMut(usize, u8) -> Result<(), ParseError>,
+) -> Result<usize, StreamError> {
+    let mut count = 0;
+    loop {
+        let buf = reader.fill_buf()?;
+        if buf.is_empty() {
+            return Ok(count);
+        }
+        let len = buf.len();
+        for b in buf {
+            f(count, *b)?;
+            count += 1;
+        }
+        reader.consume(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let mut seen = Vec::new();
+        let count = lines(&mut "a\r\nb\n\nc".as_bytes(), |l| {
+            seen.push((l.line(), l.text().to_string()));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(count, 4);
+        assert_eq!(
+            seen,
+            vec![
+                (1, "a".to_string()),
+                (2, "b".to_string()),
+                (3, String::new()),
+                (4, "c".to_string())
+            ]
+        );
+
+        let err = lines(&mut "1\nx\n".as_bytes(), |mut l| {
+            l.number::<u8>().map(|_| ())
+        });
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "line 2, column 1: expected a number, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_bytes() {
+        let mut seen = Vec::new();
+        let count = bytes(&mut "abc".as_bytes(), |i, b| {
+            seen.push((i, b));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(seen, vec![(0, b'a'), (1, b'b'), (2, b'c')]);
+    }
+}
@@ -0,0 +1,178 @@
+//! What every elf carries, with some statistics on top.
+use aoc_common::parse::{Lines, Scanner};
+use aoc_common::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    items: Vec<u32>,
+    total: u64,
+}
+
+impl Elf {
+    /// Calories of every item, in the order they were listed.
+    pub fn items(&self) -> &[u32] {
+        &self.items
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Sum of the item calories. A `u64` has room for 2^32 of the largest items,
+    /// which is more than fits in memory.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+/// The elves in input order. There's always at least one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    elves: Vec<Elf>,
+    // Totals of all elves, ascending.
+    sorted: Vec<u64>,
+}
+
+impl Inventory {
+    /// Elves are separated by blank (or whitespace-only) lines. Every other
+    /// line has to be a single number of calories.
+    pub fn parse(inp: &str) -> Result<Self, ParseError> {
+        let elves = Lines::blocks(inp)
+            .into_iter()
+            .map(|block| {
+                let items = block
+                    .map(|mut line| parse_calories(&mut line))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let total = items.iter().map(|i| *i as u64).sum();
+                Ok(Elf { items, total })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if elves.is_empty() {
+            return Err(ParseError::at_end(inp, "a number of calories"));
+        }
+
+        let mut sorted: Vec<u64> = elves.iter().map(Elf::total).collect();
+        sorted.sort_unstable();
+        Ok(Inventory { elves, sorted })
+    }
+
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    /// How many items each elf carries, in input order.
+    pub fn item_counts(&self) -> Vec<usize> {
+        self.elves.iter().map(Elf::item_count).collect()
+    }
+
+    /// The `k` highest totals, highest first. Fewer if there aren't `k` elves.
+    pub fn top_n(&self, k: usize) -> Vec<u64> {
+        self.sorted.iter().rev().take(k).copied().collect()
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.sorted.iter().sum::<u64>() as f64 / self.sorted.len() as f64
+    }
+
+    /// The middle total, or the mean of the middle two.
+    pub fn median(&self) -> f64 {
+        let n = self.sorted.len();
+        match n % 2 {
+            0 => (self.sorted[n / 2 - 1] + self.sorted[n / 2]) as f64 / 2.0,
+            _ => self.sorted[n / 2] as f64,
+        }
+    }
+
+    /// The smallest total that at least `p` percent of the elves don't exceed
+    /// (the nearest-rank method). Panics unless `p` is within 0 to 100.
+    pub fn percentile(&self, p: f64) -> u64 {
+        assert!((0.0..=100.0).contains(&p), "percentile {} out of range", p);
+        let rank = (p / 100.0 * self.sorted.len() as f64).ceil() as usize;
+        self.sorted[rank.max(1) - 1]
+    }
+}
+
+pub(crate) fn parse_calories(line: &mut Scanner) -> Result<u32, ParseError> {
+    let calories = line.number()?;
+    line.end()?;
+    Ok(calories)
+}
+
+/// Keeps the `k` highest totals pushed to it, highest first.
+#[derive(Debug, Clone)]
+pub(crate) struct TopN {
+    top: Vec<u64>,
+    k: usize,
+}
+
+impl TopN {
+    pub fn new(k: usize) -> Self {
+        TopN {
+            top: Vec::with_capacity(k + 1),
+            k,
+        }
+    }
+
+    pub fn push(&mut self, total: u64) {
+        let i = self.top.partition_point(|t| *t >= total);
+        if i < self.k {
+            self.top.insert(i, total);
+            self.top.truncate(self.k);
+        }
+    }
+
+    pub fn into_vec(self) -> Vec<u64> {
+        self.top
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let inv = Inventory::parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(inv.item_counts(), vec![3, 1, 2, 3, 1]);
+        assert_eq!(inv.elves()[1].items(), &[4000]);
+        assert_eq!(inv.top_n(2), vec![24000, 11000]);
+        assert_eq!(inv.top_n(9).len(), 5);
+        assert_eq!(inv.mean(), 11000.0);
+        assert_eq!(inv.median(), 10000.0);
+        assert_eq!(inv.percentile(0.0), 4000);
+        assert_eq!(inv.percentile(40.0), 6000);
+        assert_eq!(inv.percentile(41.0), 10000);
+        assert_eq!(inv.percentile(100.0), 24000);
+
+        let inv = Inventory::parse("1\n\n4294967295\n4294967295\n").unwrap();
+        assert_eq!(inv.median(), 4294967295.5);
+        assert_eq!(inv.top_n(1), vec![8589934590]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Inventory::parse("1000\n2000\n--\n3000\n")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 1: expected a number, found `--`"
+        );
+        assert_eq!(
+            Inventory::parse("1000 2000\n").unwrap_err().to_string(),
+            "line 1, column 5: expected end of line, found ' '"
+        );
+        assert_eq!(
+            Inventory::parse(" \n\n").unwrap_err().to_string(),
+            "line 3, column 1: expected a number of calories, found end of input"
+        );
+    }
+
+    #[test]
+    fn test_top_n() {
+        let mut top = TopN::new(3);
+        for total in [5, 1, 9, 5, 7, 2] {
+            top.push(total);
+        }
+        assert_eq!(top.into_vec(), vec![9, 7, 5]);
+    }
+}
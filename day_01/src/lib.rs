@@ -0,0 +1,97 @@
+use aoc_common::{debug, stream, Answer, ParseError, Solver, StreamError};
+use inventory::{parse_calories, Inventory, TopN};
+use std::io::BufRead;
+
+pub mod inventory;
+
+#[derive(Default)]
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input = Inventory;
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        let inv = Inventory::parse(inp)?;
+        debug!(
+            "{} elves, mean {:.1}, median {:.1}, 90th percentile {}",
+            inv.elves().len(),
+            inv.mean(),
+            inv.median(),
+            inv.percentile(90.0)
+        );
+        Ok(inv)
+    }
+
+    fn part_1(&self, inv: &Self::Input) -> Answer {
+        inv.top_n(1)[0].into()
+    }
+
+    fn part_2(&self, inv: &Self::Input) -> Option<Answer> {
+        Some(inv.top_n(3).iter().sum::<u64>().into())
+    }
+
+    fn stream(
+        &self,
+        input: &mut dyn BufRead,
+    ) -> Option<Result<(Answer, Option<Answer>), StreamError>> {
+        Some(top_3(input).map(|top| (top[0].into(), Some(top.iter().sum::<u64>().into()))))
+    }
+}
+
+/// The 3 highest totals, highest first, without keeping the other elves around.
+fn top_3(input: &mut dyn BufRead) -> Result<Vec<u64>, StreamError> {
+    let mut top = TopN::new(3);
+    let mut elf = None;
+    let mut elves = 0;
+
+    let lines = stream::lines(input, |mut line| {
+        match line.text().trim().is_empty() {
+            true => {
+                if let Some(total) = elf.take() {
+                    top.push(total);
+                }
+            }
+            false => {
+                if elf.is_none() {
+                    elves += 1;
+                }
+                *elf.get_or_insert(0) += parse_calories(&mut line)? as u64;
+            }
+        }
+        Ok(())
+    })?;
+    if let Some(total) = elf {
+        top.push(total);
+    }
+
+    match elves {
+        0 => Err(ParseError::new(lines + 1, 1, "a number of calories", "end of input").into()),
+        _ => Ok(top.into_vec()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day01.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day01.part_1(&inp), Answer::UInt(24000));
+        assert_eq!(Day01.part_2(&inp), Some(Answer::UInt(45000)));
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut inp = include_str!("../example.txt").as_bytes();
+        let (p1, p2) = Day01.stream(&mut inp).unwrap().unwrap();
+        assert_eq!(p1, Answer::UInt(24000));
+        assert_eq!(p2, Some(Answer::UInt(45000)));
+
+        let err = Day01.stream(&mut "\n\n".as_bytes()).unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected a number of calories, found end of input"
+        );
+    }
+}
@@ -0,0 +1,228 @@
+//! Games like Rock Paper Scissors, with any odd number of moves.
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A move of a cyclic game. Every move beats the `(n - 1) / 2` moves before it
+/// in `ALL`, wrapping around, and loses to the ones after it. That only works
+/// out for an odd number of moves.
+pub trait Move: Copy + Eq + fmt::Debug + 'static {
+    const ALL: &'static [Self];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|m| *m == self).unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rps {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move for Rps {
+    const ALL: &'static [Self] = &[Rps::Rock, Rps::Paper, Rps::Scissors];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+}
+
+/// The rules and scores of a game with the moves `M`. A round scores the
+/// player's move plus the outcome.
+#[derive(Debug, Clone)]
+pub struct Game<M> {
+    move_scores: Vec<u32>,
+    outcome_scores: [u32; 3],
+    moves: PhantomData<M>,
+}
+
+impl<M: Move> Default for Game<M> {
+    fn default() -> Self {
+        Game::new()
+    }
+}
+
+impl<M: Move> Game<M> {
+    /// Scores the moves 1, 2, 3, ... in the order of `M::ALL`, and a loss,
+    /// draw or win 0, 3 or 6, like the puzzle. Panics for an even number of
+    /// moves.
+    pub fn new() -> Self {
+        assert!(
+            M::ALL.len() % 2 == 1,
+            "a cyclic game needs an odd number of moves, not {}",
+            M::ALL.len()
+        );
+        Game {
+            move_scores: (1..=M::ALL.len() as u32).collect(),
+            outcome_scores: [0, 3, 6],
+            moves: PhantomData,
+        }
+    }
+
+    pub fn move_score(&self, m: M) -> u32 {
+        self.move_scores[m.index()]
+    }
+
+    pub fn set_move_score(&mut self, m: M, score: u32) {
+        self.move_scores[m.index()] = score;
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> u32 {
+        self.outcome_scores[outcome as usize]
+    }
+
+    pub fn set_outcome_score(&mut self, outcome: Outcome, score: u32) {
+        self.outcome_scores[outcome as usize] = score;
+    }
+
+    pub fn outcome(&self, mine: M, theirs: M) -> Outcome {
+        let n = M::ALL.len();
+        match (mine.index() + n - theirs.index()) % n {
+            0 => Outcome::Draw,
+            d if d <= n / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    /// The move that gets the `outcome` against `theirs`.
+    pub fn move_for(&self, outcome: Outcome, theirs: M) -> M {
+        let n = M::ALL.len();
+        let offset = match outcome {
+            Outcome::Lose => n - 1,
+            Outcome::Draw => 0,
+            Outcome::Win => 1,
+        };
+        M::ALL[(theirs.index() + offset) % n]
+    }
+
+    pub fn score(&self, mine: M, theirs: M) -> u64 {
+        self.move_score(mine) as u64 + self.outcome_score(self.outcome(mine, theirs)) as u64
+    }
+
+    /// The average score per round of every move against the opponent's
+    /// `moves`, best first. Ties keep the order of `M::ALL`.
+    pub fn responses(&self, moves: impl IntoIterator<Item = M>) -> Vec<(M, f64)> {
+        let mut counts = vec![0u64; M::ALL.len()];
+        for m in moves {
+            counts[m.index()] += 1;
+        }
+        let rounds = counts.iter().sum::<u64>().max(1) as f64;
+
+        let mut res: Vec<_> = M::ALL
+            .iter()
+            .map(|mine| {
+                let total: u64 = M::ALL
+                    .iter()
+                    .zip(&counts)
+                    .map(|(theirs, c)| c * self.score(*mine, *theirs))
+                    .sum();
+                (*mine, total as f64 / rounds)
+            })
+            .collect();
+        res.sort_by(|a, b| b.1.total_cmp(&a.1));
+        res
+    }
+
+    /// The single move that scores best against the opponent's `moves`.
+    pub fn best_response(&self, moves: impl IntoIterator<Item = M>) -> (M, f64) {
+        self.responses(moves)[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // In cyclic order: every move beats the two before it.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Rpsls {
+        Rock,
+        Spock,
+        Paper,
+        Lizard,
+        Scissors,
+    }
+
+    impl Move for Rpsls {
+        const ALL: &'static [Self] = &[
+            Rpsls::Rock,
+            Rpsls::Spock,
+            Rpsls::Paper,
+            Rpsls::Lizard,
+            Rpsls::Scissors,
+        ];
+    }
+
+    #[test]
+    fn test_rps() {
+        let game = Game::<Rps>::new();
+        assert_eq!(game.outcome(Rps::Paper, Rps::Rock), Outcome::Win);
+        assert_eq!(game.outcome(Rps::Rock, Rps::Scissors), Outcome::Win);
+        assert_eq!(game.outcome(Rps::Rock, Rps::Paper), Outcome::Lose);
+        assert_eq!(game.move_for(Outcome::Lose, Rps::Rock), Rps::Scissors);
+        assert_eq!(game.score(Rps::Scissors, Rps::Paper), 9);
+    }
+
+    #[test]
+    fn test_rpsls() {
+        use Rpsls::*;
+        let game = Game::<Rpsls>::new();
+        // Scissors cuts paper, paper covers rock, rock crushes lizard, lizard
+        // poisons Spock, Spock smashes scissors, scissors decapitates lizard,
+        // lizard eats paper, paper disproves Spock, Spock vaporizes rock, and
+        // as it always has, rock crushes scissors.
+        for (winner, loser) in [
+            (Scissors, Paper),
+            (Paper, Rock),
+            (Rock, Lizard),
+            (Lizard, Spock),
+            (Spock, Scissors),
+            (Scissors, Lizard),
+            (Lizard, Paper),
+            (Paper, Spock),
+            (Spock, Rock),
+            (Rock, Scissors),
+        ] {
+            assert_eq!(game.outcome(winner, loser), Outcome::Win);
+            assert_eq!(game.outcome(loser, winner), Outcome::Lose);
+        }
+        for m in Rpsls::ALL {
+            for outcome in Outcome::ALL {
+                assert_eq!(game.outcome(game.move_for(outcome, *m), *m), outcome);
+            }
+        }
+    }
+
+    #[test]
+    fn test_best_response() {
+        let mut game = Game::<Rps>::new();
+        let moves = [Rps::Rock, Rps::Rock, Rps::Scissors];
+        assert_eq!(game.best_response(moves), (Rps::Paper, 6.0));
+
+        // Once draws are worth more than wins, copying the favorite pays off.
+        game.set_outcome_score(Outcome::Draw, 10);
+        assert_eq!(game.best_response(moves), (Rps::Rock, 29.0 / 3.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "odd number of moves")]
+    fn test_even_game() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Coin {
+            Heads,
+            Tails,
+        }
+        impl Move for Coin {
+            const ALL: &'static [Self] = &[Coin::Heads, Coin::Tails];
+        }
+        Game::<Coin>::new();
+    }
+}
@@ -0,0 +1,208 @@
+use aoc_common::parse::{Lines, Scanner};
+use aoc_common::{parse_param, stream, Answer, ParamError, ParseError, Solver, StreamError};
+use game::{Game, Outcome, Rps};
+use std::io::BufRead;
+
+pub mod game;
+
+#[derive(Default)]
+pub struct Day02 {
+    pub game: Game<Rps>,
+}
+
+/// The guide's second column, a move in part 1 and an outcome in part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+impl Column {
+    fn as_move(self) -> Rps {
+        match self {
+            Column::X => Rps::Rock,
+            Column::Y => Rps::Paper,
+            Column::Z => Rps::Scissors,
+        }
+    }
+
+    fn as_outcome(self) -> Outcome {
+        match self {
+            Column::X => Outcome::Lose,
+            Column::Y => Outcome::Draw,
+            Column::Z => Outcome::Win,
+        }
+    }
+}
+
+// Scores that can be overridden with `--param`.
+const MOVE_PARAMS: [(&str, Rps); 3] = [
+    ("rock", Rps::Rock),
+    ("paper", Rps::Paper),
+    ("scissors", Rps::Scissors),
+];
+const OUTCOME_PARAMS: [(&str, Outcome); 3] = [
+    ("lose", Outcome::Lose),
+    ("draw", Outcome::Draw),
+    ("win", Outcome::Win),
+];
+
+impl Solver for Day02 {
+    // (opponent's move, second column of the guide)
+    type Input = Vec<(Rps, Column)>;
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let moves = MOVE_PARAMS
+            .iter()
+            .map(|(name, m)| (*name, self.game.move_score(*m).to_string()));
+        let outcomes = OUTCOME_PARAMS
+            .iter()
+            .map(|(name, o)| (*name, self.game.outcome_score(*o).to_string()));
+        moves.chain(outcomes).collect()
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        if let Some((_, m)) = MOVE_PARAMS.iter().find(|(n, _)| *n == name) {
+            self.game.set_move_score(*m, parse_param(name, value)?);
+        } else if let Some((_, o)) = OUTCOME_PARAMS.iter().find(|(n, _)| *n == name) {
+            self.game.set_outcome_score(*o, parse_param(name, value)?);
+        } else {
+            return Err(ParamError::unknown(name, self.params()));
+        }
+        Ok(())
+    }
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        Lines::new(inp)
+            .map(|mut round| parse_round(&mut round))
+            .collect()
+    }
+
+    fn part_1(&self, rounds: &Self::Input) -> Answer {
+        rounds
+            .iter()
+            .map(|&(theirs, col)| self.game.score(col.as_move(), theirs))
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part_2(&self, rounds: &Self::Input) -> Option<Answer> {
+        let score: u64 = rounds
+            .iter()
+            .map(|&(theirs, col)| self.round_for(col.as_outcome(), theirs))
+            .sum();
+        Some(score.into())
+    }
+
+    fn stream(
+        &self,
+        input: &mut dyn BufRead,
+    ) -> Option<Result<(Answer, Option<Answer>), StreamError>> {
+        let (mut p1, mut p2) = (0u64, 0u64);
+        let res = stream::lines(input, |mut round| {
+            let (theirs, col) = parse_round(&mut round)?;
+            p1 += self.game.score(col.as_move(), theirs);
+            p2 += self.round_for(col.as_outcome(), theirs);
+            Ok(())
+        });
+        Some(res.map(|_| (p1.into(), Some(p2.into()))))
+    }
+
+    fn report(&self, rounds: &Self::Input) -> Option<String> {
+        let mut out = "Always playing the same move against the guide:\n".to_string();
+        for (mine, score) in self.game.responses(rounds.iter().map(|r| r.0)) {
+            out += &format!("{:?}: {:.2} per round\n", mine, score);
+        }
+        Some(out)
+    }
+}
+
+impl Day02 {
+    /// The score of the move that gets the `outcome` in part 2.
+    fn round_for(&self, outcome: Outcome, theirs: Rps) -> u64 {
+        self.game.score(self.game.move_for(outcome, theirs), theirs)
+    }
+}
+
+fn parse_round(round: &mut Scanner) -> Result<(Rps, Column), ParseError> {
+    let theirs = match round.one_of("ABC")? {
+        'A' => Rps::Rock,
+        'B' => Rps::Paper,
+        _ => Rps::Scissors,
+    };
+    round.tag(" ")?;
+    let col = match round.one_of("XYZ")? {
+        'X' => Column::X,
+        'Y' => Column::Y,
+        _ => Column::Z,
+    };
+    round.end()?;
+    Ok((theirs, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day02::default()
+            .parse(include_str!("../example.txt"))
+            .unwrap();
+        assert_eq!(Day02::default().part_1(&inp), Answer::UInt(15));
+        assert_eq!(Day02::default().part_2(&inp), Some(Answer::UInt(12)));
+    }
+
+    #[test]
+    fn test_report() {
+        let day = Day02::default();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(
+            day.report(&inp).unwrap(),
+            "Always playing the same move against the guide:\n\
+             Scissors: 6.00 per round\n\
+             Paper: 5.00 per round\n\
+             Rock: 4.00 per round\n"
+        );
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut inp = include_str!("../example.txt").as_bytes();
+        let (p1, p2) = Day02::default().stream(&mut inp).unwrap().unwrap();
+        assert_eq!(p1, Answer::UInt(15));
+        assert_eq!(p2, Some(Answer::UInt(12)));
+    }
+
+    #[test]
+    fn test_scoring() {
+        let mut day = Day02::default();
+        day.set_param("win", "10").unwrap();
+        day.set_param("rock", "0").unwrap();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        // A win with paper (12), a loss with rock (0) and a draw with scissors (6).
+        assert_eq!(day.part_1(&inp), Answer::UInt(18));
+        assert_eq!(
+            day.set_param("lizard", "4").unwrap_err().to_string(),
+            ParamError::unknown("lizard", day.params()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_large_scores() {
+        let mut day = Day02::default();
+        for name in ["rock", "paper", "scissors"] {
+            day.set_param(name, &u32::MAX.to_string()).unwrap();
+        }
+        let total = Answer::UInt(3 * u32::MAX as u64 + 9);
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(day.part_1(&inp), total);
+        assert_eq!(day.part_2(&inp), Some(total.clone()));
+        let mut inp = include_str!("../example.txt").as_bytes();
+        assert_eq!(
+            day.stream(&mut inp).unwrap().unwrap(),
+            (total.clone(), Some(total))
+        );
+    }
+}
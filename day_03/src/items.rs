@@ -0,0 +1,111 @@
+use std::fmt;
+use std::ops::{BitAnd, BitOr};
+
+/// A set of items, as one bit per priority: bit 1 for `a` up to bit 52 for `Z`.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct Items(u64);
+
+impl Items {
+    /// Every item there is.
+    pub const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    /// Panics for anything but a-z and A-Z.
+    pub fn insert(&mut self, item: char) {
+        let p = priority(item).unwrap_or_else(|| panic!("{:?} is not an item", item));
+        self.0 |= 1 << p;
+    }
+
+    pub fn contains(self, item: char) -> bool {
+        priority(item).is_some_and(|p| self.0 & (1 << p) != 0)
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The item with the lowest priority.
+    pub fn first(self) -> Option<char> {
+        match self.is_empty() {
+            true => None,
+            false => Some(item(self.0.trailing_zeros())),
+        }
+    }
+
+    /// The items from the lowest priority to the highest.
+    pub fn iter(self) -> impl Iterator<Item = char> {
+        (1..=52).filter(move |p| self.0 & (1 << p) != 0).map(item)
+    }
+}
+
+impl BitAnd for Items {
+    type Output = Items;
+
+    fn bitand(self, rhs: Items) -> Items {
+        Items(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Items {
+    type Output = Items;
+
+    fn bitor(self, rhs: Items) -> Items {
+        Items(self.0 | rhs.0)
+    }
+}
+
+impl FromIterator<char> for Items {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut items = Items::default();
+        for c in iter {
+            items.insert(c);
+        }
+        items
+    }
+}
+
+impl fmt::Debug for Items {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// 1 through 26 for a-z, 27 through 52 for A-Z.
+pub fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+fn item(priority: u32) -> char {
+    match priority {
+        1..=26 => (b'a' + priority as u8 - 1) as char,
+        _ => (b'A' + priority as u8 - 27) as char,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_items() {
+        let a: Items = "vJrwpWtwJgWr".chars().collect();
+        let b: Items = "hcsFMMfFFhFp".chars().collect();
+        assert_eq!((a & b).iter().collect::<String>(), "p");
+        assert_eq!((a | b).len(), 14);
+        assert!(a.contains('J') && !a.contains('j'));
+        assert_eq!(Items::ALL.len(), 52);
+        assert_eq!(Items::ALL.first(), Some('a'));
+        assert_eq!(Items::default().first(), None);
+        assert_eq!(
+            (1..=52).map(item).filter_map(priority).sum::<u32>(),
+            52 * 53 / 2
+        );
+    }
+}
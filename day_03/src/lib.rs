@@ -0,0 +1,204 @@
+use aoc_common::parse::{Lines, Scanner};
+use aoc_common::{parse_param, stream, Answer, ParamError, ParseError, Solver, StreamError};
+use items::Items;
+use std::io::BufRead;
+
+pub mod items;
+
+/// The items in each compartment.
+type Rucksack = (Items, Items);
+
+pub struct Day03 {
+    /// Elves per group, who share a badge.
+    pub group_size: usize,
+}
+
+impl Default for Day03 {
+    fn default() -> Self {
+        Day03 { group_size: 3 }
+    }
+}
+
+impl Solver for Day03 {
+    type Input = Vec<Rucksack>;
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("group_size", self.group_size.to_string())]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "group_size" => match parse_param(name, value)? {
+                0 => {
+                    return Err(ParamError::Invalid {
+                        name: name.to_string(),
+                        value: value.to_string(),
+                    })
+                }
+                n => self.group_size = n,
+            },
+            _ => return Err(ParamError::unknown(name, self.params())),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        Lines::new(inp)
+            .map(|mut line| parse_rucksack(&mut line))
+            .collect()
+    }
+
+    fn part_1(&self, rucksacks: &Self::Input) -> Answer {
+        rucksacks.iter().map(misplaced_priority).sum::<u32>().into()
+    }
+
+    fn part_2(&self, rucksacks: &Self::Input) -> Option<Answer> {
+        let groups = rucksacks.chunks(self.group_size);
+        Some(groups.map(badge_priority).sum::<u32>().into())
+    }
+
+    fn stream(
+        &self,
+        input: &mut dyn BufRead,
+    ) -> Option<Result<(Answer, Option<Answer>), StreamError>> {
+        let (mut p1, mut p2) = (0, 0);
+        let mut group = Vec::with_capacity(self.group_size);
+        let res = stream::lines(input, |mut line| {
+            let rucksack = parse_rucksack(&mut line)?;
+            p1 += misplaced_priority(&rucksack);
+            group.push(rucksack);
+            if group.len() == self.group_size {
+                p2 += badge_priority(&group);
+                group.clear();
+            }
+            Ok(())
+        });
+        Some(res.map(|_| {
+            // Like `chunks`, a last group can be short.
+            if !group.is_empty() {
+                p2 += badge_priority(&group);
+            }
+            (p1.into(), Some(p2.into()))
+        }))
+    }
+
+    fn report(&self, rucksacks: &Self::Input) -> Option<String> {
+        let mut out = String::new();
+        let mut odd = 0;
+        for (i, (comp_1, comp_2)) in rucksacks.iter().enumerate() {
+            let shared = *comp_1 & *comp_2;
+            odd += (shared.len() != 1) as usize;
+            out += &format!("Rucksack {}: {}\n", i + 1, describe(shared));
+        }
+        for (i, group) in rucksacks.chunks(self.group_size).enumerate() {
+            let shared = badges(group);
+            odd += (shared.len() != 1) as usize;
+            let first = i * self.group_size + 1;
+            let last = first + group.len() - 1;
+            out += &format!(
+                "Group {} (rucksacks {}-{}): {}\n",
+                i + 1,
+                first,
+                last,
+                describe(shared)
+            );
+        }
+        out += &format!(
+            "{} rucksacks or groups without exactly one shared item\n",
+            odd
+        );
+        Some(out)
+    }
+}
+
+fn parse_rucksack(line: &mut Scanner) -> Result<Rucksack, ParseError> {
+    let rucksack = line.rest();
+    while !line.is_empty() {
+        line.symbol("an item (a-z or A-Z)", items::priority)?;
+    }
+    if !rucksack.len().is_multiple_of(2) {
+        return Err(line.error("an even number of items"));
+    }
+    let (comp_1, comp_2) = rucksack.split_at(rucksack.len() / 2);
+    Ok((comp_1.chars().collect(), comp_2.chars().collect()))
+}
+
+/// The priority of the item that's in both compartments. If there's more than
+/// one, the lowest counts.
+fn misplaced_priority((comp_1, comp_2): &Rucksack) -> u32 {
+    item_priority((*comp_1 & *comp_2).first())
+}
+
+/// The items that every elf in the group carries.
+fn badges(group: &[Rucksack]) -> Items {
+    group
+        .iter()
+        .fold(Items::ALL, |shared, (c1, c2)| shared & (*c1 | *c2))
+}
+
+/// The priority of the group's badge, the lowest if there's more than one.
+fn badge_priority(group: &[Rucksack]) -> u32 {
+    item_priority(badges(group).first())
+}
+
+fn item_priority(item: Option<char>) -> u32 {
+    item.and_then(items::priority).unwrap_or(0)
+}
+
+/// Every item with its priority, like `p (16), L (38)`.
+fn describe(items: Items) -> String {
+    let all: Vec<_> = items
+        .iter()
+        .map(|i| format!("{} ({})", i, item_priority(Some(i))))
+        .collect();
+    match all.is_empty() {
+        true => "nothing".to_string(),
+        false => all.join(", "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day03::default()
+            .parse(include_str!("../example.txt"))
+            .unwrap();
+        assert_eq!(Day03::default().part_1(&inp), Answer::UInt(157));
+        assert_eq!(Day03::default().part_2(&inp), Some(Answer::UInt(70)));
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut inp = include_str!("../example.txt").as_bytes();
+        let (p1, p2) = Day03::default().stream(&mut inp).unwrap().unwrap();
+        assert_eq!(p1, Answer::UInt(157));
+        assert_eq!(p2, Some(Answer::UInt(70)));
+    }
+
+    #[test]
+    fn test_group_size() {
+        let mut day = Day03::default();
+        day.set_param("group_size", "2").unwrap();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        // Pairs share several items, the lowest of which count: f, q and G.
+        assert_eq!(day.part_2(&inp), Some(Answer::UInt(6 + 17 + 33)));
+        assert!(day.set_param("group_size", "0").is_err());
+    }
+
+    #[test]
+    fn test_report() {
+        let day = Day03::default();
+        let inp = day.parse("abcA\nbBbB\nACde\n").unwrap();
+        assert_eq!(
+            day.report(&inp).unwrap(),
+            "Rucksack 1: nothing\n\
+             Rucksack 2: b (2), B (28)\n\
+             Rucksack 3: nothing\n\
+             Group 1 (rucksacks 1-3): nothing\n\
+             4 rucksacks or groups without exactly one shared item\n"
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt;
+
+/// An inclusive range of sections.
+pub type Range = (u32, u32);
+
+/// A set of sections, stored as sorted ranges that neither overlap nor touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<Range>,
+}
+
+impl IntervalSet {
+    /// All sections from `start` through `end`, or none if `end < start`.
+    pub fn from_range((start, end): Range) -> Self {
+        match start <= end {
+            true => IntervalSet {
+                ranges: vec![(start, end)],
+            },
+            false => IntervalSet::default(),
+        }
+    }
+
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of sections in the set.
+    pub fn coverage(&self) -> u64 {
+        self.ranges.iter().map(|(s, e)| (e - s) as u64 + 1).sum()
+    }
+
+    pub fn contains(&self, section: u32) -> bool {
+        let i = self.ranges.partition_point(|(_, e)| *e < section);
+        self.ranges.get(i).is_some_and(|(s, _)| *s <= section)
+    }
+
+    pub fn is_subset(&self, other: &IntervalSet) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Adds a range, merging it with any it overlaps or touches.
+    pub fn insert(&mut self, (start, end): Range) {
+        if start > end {
+            return;
+        }
+        // The ranges before `lo` end too early to merge, the ones from `hi`
+        // start too late.
+        let lo = self
+            .ranges
+            .partition_point(|(_, e)| e.saturating_add(1) < start);
+        let hi = self
+            .ranges
+            .partition_point(|(s, _)| *s <= end.saturating_add(1));
+        let merged = match lo < hi {
+            true => (start.min(self.ranges[lo].0), end.max(self.ranges[hi - 1].1)),
+            false => (start, end),
+        };
+        self.ranges.splice(lo..hi, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut res = self.clone();
+        for r in &other.ranges {
+            res.insert(*r);
+        }
+        res
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (mut i, mut j) = (0, 0);
+        let mut ranges = Vec::new();
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            let (start, end) = (a.0.max(b.0), a.1.min(b.1));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // Whichever ends first can't overlap anything after the other.
+            match a.1 < b.1 {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for &(mut start, end) in &self.ranges {
+            while j < other.ranges.len() && other.ranges[j].1 < start {
+                j += 1;
+            }
+            let mut k = j;
+            while start <= end {
+                match other.ranges.get(k) {
+                    Some(&(s, e)) if s <= end => {
+                        if s > start {
+                            ranges.push((start, s - 1));
+                        }
+                        match e.checked_add(1) {
+                            Some(next) => start = next.max(start),
+                            None => break,
+                        }
+                        k += 1;
+                    }
+                    _ => {
+                        ranges.push((start, end));
+                        break;
+                    }
+                }
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl FromIterator<Range> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range>>(iter: I) -> Self {
+        let mut ranges: Vec<_> = iter.into_iter().filter(|(s, e)| s <= e).collect();
+        ranges.sort_unstable();
+        let mut set = IntervalSet::default();
+        for (start, end) in ranges {
+            match set.ranges.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => set.ranges.push((start, end)),
+            }
+        }
+        set
+    }
+}
+
+/// Like `2-4, 6, 8-9`, or `nothing`.
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "nothing");
+        }
+        for (i, (start, end)) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match start == end {
+                true => write!(f, "{}", start)?,
+                false => write!(f, "{}-{}", start, end)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How many of the `ranges` cover each section, as consecutive runs of
+/// sections with the same count. Sections that none cover are left out.
+pub fn depths(ranges: &[Range]) -> Vec<(Range, usize)> {
+    // Every range adds one from its start, and takes it away after its end.
+    let mut events: Vec<(u64, i64)> = ranges
+        .iter()
+        .filter(|(s, e)| s <= e)
+        .flat_map(|&(s, e)| [(s as u64, 1), (e as u64 + 1, -1)])
+        .collect();
+    events.sort_unstable();
+
+    let mut res = Vec::new();
+    let mut depth = 0;
+    for (i, (at, change)) in events.iter().enumerate() {
+        depth += change;
+        match events.get(i + 1) {
+            Some((next, _)) if next > at && depth > 0 => {
+                res.push(((*at as u32, (next - 1) as u32), depth as usize));
+            }
+            _ => {}
+        }
+    }
+    res
+}
+
+/// The sections that at least `k` of the `ranges` cover.
+pub fn covered_by(ranges: &[Range], k: usize) -> IntervalSet {
+    depths(ranges)
+        .into_iter()
+        .filter(|(_, depth)| *depth >= k)
+        .map(|(r, _)| r)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range]) -> IntervalSet {
+        ranges.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut s = set(&[(6, 8), (2, 4), (3, 5), (10, 10)]);
+        assert_eq!(s.ranges(), &[(2, 8), (10, 10)]);
+        s.insert((9, 9));
+        assert_eq!(s.ranges(), &[(2, 10)]);
+        s.insert((0, u32::MAX));
+        assert_eq!(s.ranges(), &[(0, u32::MAX)]);
+        assert_eq!(s.coverage(), 1 << 32);
+        assert_eq!(set(&[(5, 3)]), IntervalSet::default());
+    }
+
+    #[test]
+    fn test_set_ops() {
+        let a = set(&[(1, 5), (10, 20)]);
+        let b = set(&[(4, 12), (18, 18), (30, 31)]);
+        assert_eq!(a.union(&b).to_string(), "1-20, 30-31");
+        assert_eq!(a.intersection(&b).to_string(), "4-5, 10-12, 18");
+        assert_eq!(a.difference(&b).to_string(), "1-3, 13-17, 19-20");
+        assert_eq!(b.difference(&a).to_string(), "6-9, 30-31");
+        assert_eq!(a.intersection(&b).coverage(), 6);
+        assert!(set(&[(11, 12)]).is_subset(&a));
+        assert!(!b.is_subset(&a));
+        assert!(a.contains(10) && !a.contains(7) && !a.contains(21));
+        assert_eq!(
+            set(&[(0, u32::MAX)]).difference(&a).to_string(),
+            format!("0, 6-9, 21-{}", u32::MAX)
+        );
+    }
+
+    #[test]
+    fn test_depths() {
+        let ranges = [(2, 4), (6, 8), (3, 7), (4, 4), (10, 9)];
+        assert_eq!(
+            depths(&ranges),
+            vec![
+                ((2, 2), 1),
+                ((3, 3), 2),
+                ((4, 4), 3),
+                ((5, 5), 1),
+                ((6, 7), 2),
+                ((8, 8), 1)
+            ]
+        );
+        assert_eq!(covered_by(&ranges, 2).to_string(), "3-4, 6-7");
+        assert_eq!(covered_by(&ranges, 3).to_string(), "4");
+        assert_eq!(covered_by(&ranges, 4).to_string(), "nothing");
+    }
+}
@@ -0,0 +1,207 @@
+use aoc_common::parse::{Lines, Scanner};
+use aoc_common::{stream, Answer, ParseError, Solver, StreamError};
+use intervals::{IntervalSet, Range};
+use std::io::BufRead;
+
+pub mod intervals;
+
+#[derive(Default)]
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input = Vec<(Range, Range)>;
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        Lines::new(inp)
+            .map(|mut pair| parse_pair(&mut pair))
+            .collect()
+    }
+
+    fn part_1(&self, pairs: &Self::Input) -> Answer {
+        let full_overlaps = pairs
+            .iter()
+            .filter(|(a, b)| is_full_overlap(*a, *b))
+            .count();
+        full_overlaps.into()
+    }
+
+    fn part_2(&self, pairs: &Self::Input) -> Option<Answer> {
+        let partial_overlaps = pairs
+            .iter()
+            .filter(|(a, b)| is_partial_overlap(*a, *b))
+            .count();
+        Some(partial_overlaps.into())
+    }
+
+    fn stream(
+        &self,
+        input: &mut dyn BufRead,
+    ) -> Option<Result<(Answer, Option<Answer>), StreamError>> {
+        let (mut full_overlaps, mut partial_overlaps) = (0usize, 0usize);
+        let res = stream::lines(input, |mut pair| {
+            let (a, b) = parse_pair(&mut pair)?;
+            full_overlaps += usize::from(is_full_overlap(a, b));
+            partial_overlaps += usize::from(is_partial_overlap(a, b));
+            Ok(())
+        });
+        Some(res.map(|_| (full_overlaps.into(), Some(partial_overlaps.into()))))
+    }
+
+    fn report(&self, pairs: &Self::Input) -> Option<String> {
+        let mut out = String::new();
+        for (i, (a, b)) in pairs.iter().enumerate() {
+            let (a, b) = (IntervalSet::from_range(*a), IntervalSet::from_range(*b));
+            let shared = a.intersection(&b);
+            out += &format!("Pair {}: {} and {} overlap on {}", i + 1, a, b, shared);
+            if !shared.is_empty() {
+                out += &format!(" ({})", sections(&shared));
+            }
+            if a.is_subset(&b) || b.is_subset(&a) {
+                out += ", fully";
+            }
+            out += "\n";
+        }
+
+        let ranges: Vec<Range> = pairs.iter().flat_map(|(a, b)| [*a, *b]).collect();
+        let assigned: IntervalSet = ranges.iter().copied().collect();
+        let last = assigned.ranges().last().map_or(0, |r| r.1);
+        let unassigned = IntervalSet::from_range((1, last)).difference(&assigned);
+        out += &format!(
+            "{} pairs, {} overlapping fully and {} at all\n",
+            pairs.len(),
+            self.part_1(pairs),
+            self.part_2(pairs).unwrap()
+        );
+        out += &format!("Assigned: {} ({})\n", assigned, sections(&assigned));
+        out += &format!("Unassigned: {} ({})\n", unassigned, sections(&unassigned));
+
+        let depths = intervals::depths(&ranges);
+        let most = depths.iter().map(|(_, d)| *d).max().unwrap_or(0);
+        for k in 2..=most {
+            let covered = intervals::covered_by(&ranges, k);
+            out += &format!(
+                "Covered by {}+ elves: {} ({})\n",
+                k,
+                covered,
+                sections(&covered)
+            );
+        }
+        Some(out)
+    }
+}
+
+fn sections(set: &IntervalSet) -> String {
+    match set.coverage() {
+        1 => "1 section".to_string(),
+        n => format!("{} sections", n),
+    }
+}
+
+fn parse_pair(pair: &mut Scanner) -> Result<(Range, Range), ParseError> {
+    let a = parse_range(pair)?;
+    pair.tag(",")?;
+    let b = parse_range(pair)?;
+    pair.end()?;
+    Ok((a, b))
+}
+
+fn parse_range(s: &mut Scanner) -> Result<Range, ParseError> {
+    let start = s.number()?;
+    s.tag("-")?;
+    let at = s.clone();
+    let end = s.number()?;
+    if end < start {
+        return Err(at.error(format!("a section from {} on", start)));
+    }
+    Ok((start, end))
+}
+
+fn is_full_overlap(a: Range, b: Range) -> bool {
+    (a.0 <= b.0 && a.1 >= b.1) || (b.0 <= a.0 && b.1 >= a.1)
+}
+
+fn is_partial_overlap(a: Range, b: Range) -> bool {
+    a.1 >= b.0 && a.0 <= b.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day04.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day04.part_1(&inp), Answer::UInt(2));
+        assert_eq!(Day04.part_2(&inp), Some(Answer::UInt(4)));
+    }
+
+    #[test]
+    fn test_overlaps_match_interval_sets() {
+        let ranges: Vec<Range> = (1..6).flat_map(|s| (s..6).map(move |e| (s, e))).collect();
+        for &a in &ranges {
+            for &b in &ranges {
+                let (sa, sb) = (IntervalSet::from_range(a), IntervalSet::from_range(b));
+                let full = sa.is_subset(&sb) || sb.is_subset(&sa);
+                assert_eq!(is_full_overlap(a, b), full, "{:?} {:?}", a, b);
+                let partial = !sa.intersection(&sb).is_empty();
+                assert_eq!(is_partial_overlap(a, b), partial, "{:?} {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut inp = include_str!("../example.txt").as_bytes();
+        let (p1, p2) = Day04.stream(&mut inp).unwrap().unwrap();
+        assert_eq!(p1, Answer::UInt(2));
+        assert_eq!(p2, Some(Answer::UInt(4)));
+
+        let err = Day04
+            .stream(&mut "2-4,6-8\n2-4;6-8\n".as_bytes())
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: expected `,`, found `;6-8`"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Day04.parse("2-4,8-6\n").unwrap_err().to_string(),
+            "line 1, column 7: expected a section from 8 on, found `6`"
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let inp = Day04.parse(include_str!("../example.txt")).unwrap();
+        let report = Day04.report(&inp).unwrap();
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines[0], "Pair 1: 2-4 and 6-8 overlap on nothing");
+        assert_eq!(
+            lines[3],
+            "Pair 4: 2-8 and 3-7 overlap on 3-7 (5 sections), fully"
+        );
+        assert_eq!(
+            lines[4],
+            "Pair 5: 6 and 4-6 overlap on 6 (1 section), fully"
+        );
+        assert_eq!(
+            lines[6..],
+            [
+                "6 pairs, 2 overlapping fully and 4 at all",
+                "Assigned: 2-9 (8 sections)",
+                "Unassigned: 1 (1 section)",
+                "Covered by 2+ elves: 2-8 (7 sections)",
+                "Covered by 3+ elves: 2-8 (7 sections)",
+                "Covered by 4+ elves: 2-8 (7 sections)",
+                "Covered by 5+ elves: 3-7 (5 sections)",
+                "Covered by 6+ elves: 4-7 (4 sections)",
+                "Covered by 7+ elves: 4-6 (3 sections)",
+                "Covered by 8+ elves: 6 (1 section)",
+            ]
+        );
+    }
+}
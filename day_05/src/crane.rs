@@ -0,0 +1,248 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The crates on every stack, from the bottom up.
+pub type Stacks = Vec<Vec<char>>;
+
+/// Moves `count` crates from the top of stack `from` onto stack `to`. Stacks
+/// count from 1, like in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveErrorKind {
+    NoSuchStack { stack: usize, stacks: usize },
+    NotEnoughCrates { stack: usize, available: usize },
+}
+
+/// Why a move can't be made. `index` counts the moves from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveError {
+    pub index: usize,
+    pub mv: Move,
+    pub kind: MoveErrorKind,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Move {} ({}): ", self.index, self.mv)?;
+        match self.kind {
+            MoveErrorKind::NoSuchStack { stack, stacks } => {
+                write!(f, "there's no stack {}, only 1 to {}", stack, stacks)
+            }
+            MoveErrorKind::NotEnoughCrates { stack, available } => {
+                write!(f, "stack {} only has {} crates", stack, available)
+            }
+        }
+    }
+}
+
+impl Error for MoveError {}
+
+/// A model of crane, which decides in what order the crates it moves end up.
+pub trait Crane {
+    /// The order in which the `lifted` crates are put down, bottom first.
+    /// They're also given bottom first, as they were on their stack.
+    fn arrange(&self, lifted: &[char]) -> Vec<char>;
+}
+
+/// Moves one crate at a time, which turns the moved crates upside down. So
+/// does any crane that flips what it lifts.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn arrange(&self, lifted: &[char]) -> Vec<char> {
+        lifted.iter().rev().copied().collect()
+    }
+}
+
+/// Moves all crates at once, keeping their order.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn arrange(&self, lifted: &[char]) -> Vec<char> {
+        lifted.to_vec()
+    }
+}
+
+/// Moves up to `capacity` crates at once, keeping the order of each lift. A
+/// capacity of 1 is a CrateMover 9000, an unlimited one a CrateMover 9001.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitedCrane {
+    pub capacity: usize,
+}
+
+impl Crane for LimitedCrane {
+    fn arrange(&self, lifted: &[char]) -> Vec<char> {
+        lifted
+            .rchunks(self.capacity.max(1))
+            .flatten()
+            .copied()
+            .collect()
+    }
+}
+
+/// A crane by the name the `crane` parameter knows it by: `9000`, `9001` or
+/// `limited:<capacity>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CraneModel {
+    CrateMover9000,
+    CrateMover9001,
+    Limited(LimitedCrane),
+}
+
+impl FromStr for CraneModel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "9000" => Ok(CraneModel::CrateMover9000),
+            "9001" => Ok(CraneModel::CrateMover9001),
+            _ => match s.strip_prefix("limited:").map(str::parse) {
+                Some(Ok(capacity)) if capacity > 0 => {
+                    Ok(CraneModel::Limited(LimitedCrane { capacity }))
+                }
+                _ => Err(()),
+            },
+        }
+    }
+}
+
+impl fmt::Display for CraneModel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CraneModel::CrateMover9000 => write!(f, "9000"),
+            CraneModel::CrateMover9001 => write!(f, "9001"),
+            CraneModel::Limited(crane) => write!(f, "limited:{}", crane.capacity),
+        }
+    }
+}
+
+impl Crane for CraneModel {
+    fn arrange(&self, lifted: &[char]) -> Vec<char> {
+        match self {
+            CraneModel::CrateMover9000 => CrateMover9000.arrange(lifted),
+            CraneModel::CrateMover9001 => CrateMover9001.arrange(lifted),
+            CraneModel::Limited(crane) => crane.arrange(lifted),
+        }
+    }
+}
+
+/// Checks that a move can be made on the `stacks`, before it's made.
+pub fn check(stacks: &Stacks, mv: Move) -> Result<(), MoveErrorKind> {
+    for stack in [mv.from, mv.to] {
+        if stack == 0 || stack > stacks.len() {
+            return Err(MoveErrorKind::NoSuchStack {
+                stack,
+                stacks: stacks.len(),
+            });
+        }
+    }
+    let available = stacks[mv.from - 1].len();
+    match mv.count <= available {
+        true => Ok(()),
+        false => Err(MoveErrorKind::NotEnoughCrates {
+            stack: mv.from,
+            available,
+        }),
+    }
+}
+
+/// Makes a move with the `crane`. Returns the crates it lifted, bottom first,
+/// or leaves the stacks alone if the move can't be made.
+pub fn apply(crane: &dyn Crane, stacks: &mut Stacks, mv: Move) -> Result<Vec<char>, MoveErrorKind> {
+    check(stacks, mv)?;
+    let from = &mut stacks[mv.from - 1];
+    let lifted = from.split_off(from.len() - mv.count);
+    stacks[mv.to - 1].extend(crane.arrange(&lifted));
+    Ok(lifted)
+}
+
+/// Makes every move in turn, stopping at the first one that can't be made.
+pub fn run(crane: &dyn Crane, stacks: &mut Stacks, moves: &[Move]) -> Result<(), MoveError> {
+    for (i, mv) in moves.iter().enumerate() {
+        apply(crane, stacks, *mv).map_err(|kind| MoveError {
+            index: i + 1,
+            mv: *mv,
+            kind,
+        })?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stacks() -> Stacks {
+        vec![vec!['A', 'B', 'C', 'D', 'E'], vec![]]
+    }
+
+    fn move_all(crane: &dyn Crane) -> String {
+        let mut stacks = stacks();
+        let mv = Move {
+            count: 5,
+            from: 1,
+            to: 2,
+        };
+        apply(crane, &mut stacks, mv).unwrap();
+        stacks[1].iter().collect()
+    }
+
+    #[test]
+    fn test_cranes() {
+        assert_eq!(move_all(&CrateMover9000), "EDCBA");
+        assert_eq!(move_all(&CrateMover9001), "ABCDE");
+        assert_eq!(move_all(&LimitedCrane { capacity: 2 }), "DEBCA");
+        assert_eq!(move_all(&LimitedCrane { capacity: 1 }), "EDCBA");
+        assert_eq!(move_all(&LimitedCrane { capacity: 9 }), "ABCDE");
+    }
+
+    #[test]
+    fn test_crane_model() {
+        for name in ["9000", "9001", "limited:2"] {
+            assert_eq!(name.parse::<CraneModel>().unwrap().to_string(), name);
+        }
+        assert_eq!(
+            move_all(&"limited:2".parse::<CraneModel>().unwrap()),
+            "DEBCA"
+        );
+        for name in ["9002", "limited:0", "limited:", "limited:x"] {
+            assert_eq!(name.parse::<CraneModel>(), Err(()));
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let mv = |count, from, to| Move { count, from, to };
+        let mut stacks = stacks();
+        let moves = [mv(2, 1, 2), mv(3, 2, 1), mv(1, 1, 3)];
+        assert_eq!(
+            run(&CrateMover9001, &mut stacks, &moves)
+                .unwrap_err()
+                .to_string(),
+            "Move 2 (move 3 from 2 to 1): stack 2 only has 2 crates"
+        );
+        // The failed move left the stacks alone.
+        assert_eq!(stacks, vec![vec!['A', 'B', 'C'], vec!['D', 'E']]);
+
+        let err = run(&CrateMover9001, &mut stacks, &moves[2..]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Move 1 (move 1 from 1 to 3): there's no stack 3, only 1 to 2"
+        );
+    }
+}
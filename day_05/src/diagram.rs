@@ -0,0 +1,90 @@
+use crate::crane::{Crane, Move, MoveError, Stacks};
+use crate::replay::Replay;
+
+/// Draws the stacks like the puzzle input does, down to the padding, with the
+/// stack numbers underneath.
+pub fn draw(stacks: &Stacks) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut rows: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            let cells: Vec<_> = stacks
+                .iter()
+                .map(|s| match s.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            cells.join(" ")
+        })
+        .collect();
+    let numbers: Vec<_> = (1..=stacks.len()).map(|i| format!("{:^3}", i)).collect();
+    rows.push(numbers.join(" "));
+
+    let mut out = rows.join("\n");
+    out.push('\n');
+    out
+}
+
+/// The drawing before the moves, and after each of them.
+pub fn trace(crane: &dyn Crane, setup: &Stacks, moves: &[Move]) -> Result<String, MoveError> {
+    let mut replay = Replay::new(crane, setup, moves);
+    let mut out = draw(replay.stacks());
+    while let Some(mv) = replay.step()? {
+        let i = replay.position();
+        out += &format!("\nAfter move {} ({}):\n{}", i, mv, draw(replay.stacks()));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane::CrateMover9001;
+    use crate::parse_input;
+
+    #[test]
+    fn test_draw() {
+        let inp = include_str!("../example.txt");
+        let (stacks, moves) = parse_input(inp).unwrap();
+        let moves: Vec<_> = moves.iter().map(|m| format!("{}\n", m)).collect();
+        assert_eq!(format!("{}\n{}", draw(&stacks), moves.concat()), inp);
+
+        let stacks = vec![vec!['A'], vec![], vec!['B', 'C']];
+        assert_eq!(draw(&stacks), "        [C]\n[A]     [B]\n 1   2   3 \n");
+        assert_eq!(
+            parse_input(&format!("{}\nmove 1 from 1 to 2\n", draw(&stacks)))
+                .unwrap()
+                .0,
+            stacks
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let (stacks, moves) = parse_input(include_str!("../example.txt")).unwrap();
+        let trace = trace(&CrateMover9001, &stacks, &moves[..2]).unwrap();
+        assert_eq!(
+            trace,
+            concat!(
+                "    [D]    \n",
+                "[N] [C]    \n",
+                "[Z] [M] [P]\n",
+                " 1   2   3 \n",
+                "\n",
+                "After move 1 (move 1 from 2 to 1):\n",
+                "[D]        \n",
+                "[N] [C]    \n",
+                "[Z] [M] [P]\n",
+                " 1   2   3 \n",
+                "\n",
+                "After move 2 (move 3 from 1 to 3):\n",
+                "        [D]\n",
+                "        [N]\n",
+                "    [C] [Z]\n",
+                "    [M] [P]\n",
+                " 1   2   3 \n",
+            )
+        );
+    }
+}
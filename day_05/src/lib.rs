@@ -0,0 +1,230 @@
+use aoc_common::parse::Lines;
+use aoc_common::{parse_param, Answer, ParamError, ParseError, Solver};
+use crane::{Crane, CraneModel, CrateMover9000, CrateMover9001, Move, MoveErrorKind, Stacks};
+
+pub mod crane;
+pub mod diagram;
+pub mod replay;
+
+pub struct Day05 {
+    /// The crane that turns out to be used in part 2.
+    pub crane: CraneModel,
+}
+
+impl Default for Day05 {
+    fn default() -> Self {
+        Day05 {
+            crane: CraneModel::CrateMover9001,
+        }
+    }
+}
+
+impl Solver for Day05 {
+    type Input = (Stacks, Vec<Move>);
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("crane", self.crane.to_string())]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "crane" => self.crane = parse_param(name, value)?,
+            _ => return Err(ParamError::unknown(name, self.params())),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, (setup, moves): &Self::Input) -> Answer {
+        top_crates(&rearrange(&CrateMover9000, setup, moves)).into()
+    }
+
+    fn part_2(&self, (setup, moves): &Self::Input) -> Option<Answer> {
+        Some(top_crates(&rearrange(&self.crane, setup, moves)).into())
+    }
+
+    fn report(&self, (setup, moves): &Self::Input) -> Option<String> {
+        let part_2 = match self.crane {
+            CraneModel::CrateMover9000 => "CrateMover 9000".to_string(),
+            CraneModel::CrateMover9001 => "CrateMover 9001".to_string(),
+            CraneModel::Limited(crane) => format!("a crane lifting {}", crane.capacity),
+        };
+        let part_2 = format!("Part 2, {}", part_2);
+        let cranes: [(&str, &dyn Crane); 2] = [
+            ("Part 1, CrateMover 9000", &CrateMover9000),
+            (&part_2, &self.crane),
+        ];
+        let traces: Vec<_> = cranes
+            .iter()
+            .map(|(name, crane)| {
+                let trace = diagram::trace(*crane, setup, moves).unwrap();
+                format!("{}:\n\n{}", name, trace)
+            })
+            .collect();
+        Some(traces.join("\n"))
+    }
+}
+
+/// The stacks after all moves, which `parse_input` made sure are possible.
+fn rearrange(crane: &dyn Crane, setup: &Stacks, moves: &[Move]) -> Stacks {
+    let mut stacks = setup.clone();
+    crane::run(crane, &mut stacks, moves).unwrap();
+    stacks
+}
+
+fn parse_input(inp: &str) -> Result<(Stacks, Vec<Move>), ParseError> {
+    let mut blocks = Lines::blocks(inp).into_iter();
+    let setup = blocks
+        .next()
+        .ok_or_else(|| ParseError::at_end(inp, "a drawing of the stacks"))?;
+
+    // Iterate over the lines from lowest to highest.
+    let mut setup_lines: Vec<_> = setup.collect();
+    // The last line tells us how many stacks there are
+    let mut stack_numbers = setup_lines.pop().unwrap();
+    let mut stacks = Vec::with_capacity(9);
+    loop {
+        stack_numbers.skip_whitespace();
+        if stack_numbers.is_empty() {
+            break;
+        }
+        stack_numbers.number::<usize>()?;
+        stacks.push(Vec::with_capacity(8));
+    }
+
+    for mut line in setup_lines.into_iter().rev() {
+        // Each column of the stack is 3 chars wide ([A]).
+        // Columns are seperated by a single space.
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            if i > 0 && !line.is_empty() {
+                line.tag(" ")?;
+            }
+            if line.is_empty() || line.try_tag("   ") {
+                continue;
+            }
+            line.tag("[")?;
+            stack.push(line.symbol("a crate (A-Z)", |c| c.is_ascii_uppercase().then_some(c))?);
+            line.tag("]")?;
+        }
+        line.end()?;
+    }
+
+    let mut moves = Vec::new();
+    // Where the count, and the stacks to move from and to are in the input.
+    let mut fields = Vec::new();
+    let procedure = blocks
+        .next()
+        .ok_or_else(|| ParseError::at_end(inp, "the rearrangement procedure"))?;
+    for mut m in procedure {
+        m.tag("move ")?;
+        let at_count = m.clone();
+        let count = m.number()?;
+        m.tag(" from ")?;
+        let at_from = m.clone();
+        let from = m.number()?;
+        m.tag(" to ")?;
+        let at_to = m.clone();
+        let to = m.number()?;
+        m.end()?;
+        moves.push(Move { count, from, to });
+        fields.push((at_count, at_from, at_to));
+    }
+
+    // Stack heights don't depend on the crane, so any can check the moves.
+    let mut check = stacks.clone();
+    for (i, (mv, (at_count, at_from, at_to))) in moves.iter().zip(fields).enumerate() {
+        match crane::apply(&CrateMover9001, &mut check, *mv) {
+            Ok(_) => {}
+            Err(MoveErrorKind::NoSuchStack { stack, stacks }) => {
+                let at = if stack == mv.from { at_from } else { at_to };
+                let expected = format!("a stack from 1 to {} for move {}", stacks, i + 1);
+                return Err(at.error(expected));
+            }
+            Err(MoveErrorKind::NotEnoughCrates { stack, available }) => {
+                let expected = format!(
+                    "at most {} crates on stack {} for move {}",
+                    available,
+                    stack,
+                    i + 1
+                );
+                return Err(at_count.error(expected));
+            }
+        }
+    }
+
+    Ok((stacks, moves))
+}
+
+/// The crate on top of every stack that isn't empty.
+fn top_crates(stacks: &Stacks) -> String {
+    stacks.iter().filter_map(|s| s.last()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let day = Day05::default();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(day.part_1(&inp), Answer::Text("CMZ".to_string()));
+        assert_eq!(day.part_2(&inp), Some(Answer::Text("MCD".to_string())));
+    }
+
+    #[test]
+    fn test_crane_param() {
+        let mut day = Day05::default();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        for (crane, top) in [("9000", "CMZ"), ("limited:1", "CMZ"), ("limited:2", "MCZ")] {
+            day.set_param("crane", crane).unwrap();
+            assert_eq!(day.params(), vec![("crane", crane.to_string())]);
+            assert_eq!(day.part_2(&inp), Some(Answer::Text(top.to_string())));
+        }
+        assert!(day
+            .report(&inp)
+            .unwrap()
+            .contains("Part 2, a crane lifting 2:"));
+
+        assert_eq!(
+            day.set_param("crane", "limited:0").unwrap_err().to_string(),
+            "Invalid value limited:0 for parameter crane"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let inp = "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove 3 from 1\n";
+        assert_eq!(
+            parse_input(inp).unwrap_err().to_string(),
+            "line 6, column 14: expected ` to `, found end of line"
+        );
+
+        let inp = "    [D]\n[N] (C)\n 1   2\n\nmove 1 from 2 to 1\n";
+        assert_eq!(
+            parse_input(inp).unwrap_err().to_string(),
+            "line 2, column 5: expected `[`, found `(C)`"
+        );
+
+        let inp = "    [D]\n[N] [C]\n 1   2\n";
+        assert_eq!(
+            parse_input(inp).unwrap_err().to_string(),
+            "line 4, column 1: expected the rearrangement procedure, found end of input"
+        );
+
+        let inp = "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove 3 from 1 to 2\n";
+        assert_eq!(
+            parse_input(inp).unwrap_err().to_string(),
+            "line 6, column 6: expected at most 2 crates on stack 1 for move 2, found `3`"
+        );
+
+        let inp = "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 3\n";
+        assert_eq!(
+            parse_input(inp).unwrap_err().to_string(),
+            "line 5, column 18: expected a stack from 1 to 2 for move 1, found `3`"
+        );
+    }
+}
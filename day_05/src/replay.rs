@@ -0,0 +1,139 @@
+use crate::crane::{self, Crane, Move, MoveError, Stacks};
+
+/// Steps through the moves with a crane, forwards and backwards. It keeps
+/// the crates every move lifted, so it can put them back.
+pub struct Replay<'a> {
+    crane: &'a dyn Crane,
+    setup: &'a Stacks,
+    moves: &'a [Move],
+    stacks: Stacks,
+    lifted: Vec<Vec<char>>,
+}
+
+impl<'a> Replay<'a> {
+    /// Starts before the first move.
+    pub fn new(crane: &'a dyn Crane, setup: &'a Stacks, moves: &'a [Move]) -> Self {
+        Replay {
+            crane,
+            setup,
+            moves,
+            stacks: setup.clone(),
+            lifted: Vec::with_capacity(moves.len()),
+        }
+    }
+
+    pub fn stacks(&self) -> &Stacks {
+        &self.stacks
+    }
+
+    /// How many moves have been made.
+    pub fn position(&self) -> usize {
+        self.lifted.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    /// Makes the next move, or returns `None` after the last one.
+    pub fn step(&mut self) -> Result<Option<Move>, MoveError> {
+        let index = self.position();
+        let Some(&mv) = self.moves.get(index) else {
+            return Ok(None);
+        };
+        let lifted = crane::apply(self.crane, &mut self.stacks, mv).map_err(|kind| MoveError {
+            index: index + 1,
+            mv,
+            kind,
+        })?;
+        self.lifted.push(lifted);
+        Ok(Some(mv))
+    }
+
+    /// Takes back the last move, or returns `None` before the first one.
+    pub fn undo(&mut self) -> Option<Move> {
+        let lifted = self.lifted.pop()?;
+        let mv = self.moves[self.position()];
+        // Whatever the crane did with them, the lifted crates are on top.
+        let to = &mut self.stacks[mv.to - 1];
+        to.truncate(to.len() - lifted.len());
+        self.stacks[mv.from - 1].extend(lifted);
+        Some(mv)
+    }
+
+    /// Steps or undoes until `n` moves have been made. Panics if there
+    /// aren't that many moves.
+    pub fn jump(&mut self, n: usize) -> Result<(), MoveError> {
+        assert!(n <= self.len(), "there are only {} moves", self.len());
+        while self.position() > n {
+            self.undo();
+        }
+        while self.position() < n {
+            self.step()?;
+        }
+        Ok(())
+    }
+
+    /// The first move that put `item` on top of a stack, and that stack.
+    /// A crate ends up on top by being moved, or when the crates on it are
+    /// moved away. Where it was before the first move doesn't count.
+    pub fn first_on_top(&self, item: char) -> Result<Option<(usize, usize)>, MoveError> {
+        let mut replay = Replay::new(self.crane, self.setup, self.moves);
+        while let Some(mv) = replay.step()? {
+            for stack in [mv.to, mv.from] {
+                if replay.stacks[stack - 1].last() == Some(&item) {
+                    return Ok(Some((replay.position(), stack)));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane::{CrateMover9000, CrateMover9001};
+    use crate::diagram::draw;
+    use crate::parse_input;
+
+    #[test]
+    fn test_replay() {
+        let (setup, moves) = parse_input(include_str!("../example.txt")).unwrap();
+        for crane in [&CrateMover9000 as &dyn Crane, &CrateMover9001] {
+            let mut replay = Replay::new(crane, &setup, &moves);
+            let mut states = vec![replay.stacks().clone()];
+            while replay.step().unwrap().is_some() {
+                states.push(replay.stacks().clone());
+            }
+            assert_eq!(replay.position(), moves.len());
+            for n in (0..states.len()).rev() {
+                assert_eq!(replay.stacks(), &states[n]);
+                replay.undo();
+            }
+            assert_eq!(replay.undo(), None);
+
+            replay.jump(3).unwrap();
+            assert_eq!(replay.stacks(), &states[3]);
+            replay.jump(1).unwrap();
+            assert_eq!(replay.stacks(), &states[1]);
+        }
+
+        let mut replay = Replay::new(&CrateMover9000, &setup, &moves);
+        replay.jump(2).unwrap();
+        assert_eq!(
+            draw(replay.stacks()),
+            "        [Z]\n        [N]\n    [C] [D]\n    [M] [P]\n 1   2   3 \n"
+        );
+        assert_eq!(replay.first_on_top('D'), Ok(Some((1, 1))));
+        assert_eq!(replay.first_on_top('Z'), Ok(Some((2, 3))));
+        // Uncovered when the crate on it moved away.
+        assert_eq!(replay.first_on_top('C'), Ok(Some((1, 2))));
+        assert_eq!(replay.first_on_top('X'), Ok(None));
+        assert_eq!(replay.position(), 2);
+    }
+}
@@ -0,0 +1,193 @@
+use aoc_common::parse::Lines;
+use aoc_common::{stream, Answer, ParseError, Solver, StreamError};
+use markers::Markers;
+use std::io::BufRead;
+
+pub mod markers;
+
+#[derive(Default)]
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input = String;
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = Lines::new(inp);
+        let mut line = lines.expect("a datastream")?;
+        let msg = line.rest();
+        while !line.is_empty() {
+            line.symbol("a lowercase letter", |c| {
+                c.is_ascii_lowercase().then_some(c)
+            })?;
+        }
+        lines.end()?;
+        for (size, name) in MARKERS {
+            if markers::first_marker(msg.as_bytes(), size).is_none() {
+                return Err(no_marker(size, name, msg.len()));
+            }
+        }
+        Ok(msg.to_string())
+    }
+
+    fn part_1(&self, msg: &Self::Input) -> Answer {
+        get_marker_idx(msg, 4).into()
+    }
+
+    fn part_2(&self, msg: &Self::Input) -> Option<Answer> {
+        Some(get_marker_idx(msg, 14).into())
+    }
+
+    fn stream(
+        &self,
+        input: &mut dyn BufRead,
+    ) -> Option<Result<(Answer, Option<Answer>), StreamError>> {
+        Some(stream_markers(input).map(|(p1, p2)| (p1.into(), Some(p2.into()))))
+    }
+}
+
+/// Finds both markers a byte at a time, only remembering the last 14.
+fn stream_markers(input: &mut dyn BufRead) -> Result<(usize, usize), StreamError> {
+    let mut first = [None, None];
+    let len = scan(input, &MARKERS.map(|(size, _)| size), |i, at| {
+        first[i] = first[i].or(Some(at));
+    })?;
+    match first {
+        [Some(packet), Some(message)] => Ok((packet, message)),
+        _ => {
+            let i = first.iter().position(Option::is_none).unwrap();
+            let (size, name) = MARKERS[i];
+            Err(no_marker(size, name, len).into())
+        }
+    }
+}
+
+/// The sizes of the start-of-packet and start-of-message markers.
+const MARKERS: [(usize, &str); 2] = [(4, "start-of-packet"), (14, "start-of-message")];
+
+/// A datastream of `len` letters without a marker of `size`.
+fn no_marker(size: usize, name: &str, len: usize) -> ParseError {
+    let expected = format!("a {} marker ({} different letters in a row)", name, size);
+    ParseError::new(1, len + 1, expected, "end of line")
+}
+
+/// Every marker of each size in the datastream, in a single pass.
+pub fn all_markers(
+    input: &mut dyn BufRead,
+    sizes: &[usize],
+) -> Result<Vec<Vec<usize>>, StreamError> {
+    let mut all = vec![Vec::new(); sizes.len()];
+    scan(input, sizes, |i, at| all[i].push(at))?;
+    Ok(all)
+}
+
+/// Checks the datastream a byte at a time, and calls `found` with the end of
+/// every marker in it, and the index of its size. Returns the number of
+/// letters.
+fn scan(
+    input: &mut dyn BufRead,
+    sizes: &[usize],
+    mut found: impl FnMut(usize, usize),
+) -> Result<usize, StreamError> {
+    let mut markers = Markers::new(sizes);
+    // The line ending seen so far, if the datastream ended.
+    let mut end = None;
+
+    let len = stream::bytes(input, |i, b| {
+        match (end, b) {
+            (None, b'a'..=b'z') => {
+                markers.push(b);
+                for s in markers.found() {
+                    found(s, i + 1);
+                }
+            }
+            (None, b'\r' | b'\n') | (Some(b'\r'), b'\n') => end = Some(b),
+            (None, _) => {
+                let found = format!("`{}`", b.escape_ascii());
+                return Err(ParseError::new(1, i + 1, "a lowercase letter", found));
+            }
+            (Some(_), _) => {
+                return Err(ParseError::new(
+                    2,
+                    1,
+                    "end of input",
+                    format!("`{}`", b.escape_ascii()),
+                ));
+            }
+        }
+        Ok(())
+    })?;
+    match len {
+        0 => Err(ParseError::new(1, 1, "a datastream", "end of input").into()),
+        _ => Ok(markers.len()),
+    }
+}
+
+fn get_marker_idx(msg: &str, packet_size: usize) -> usize {
+    // `parse` made sure there is one.
+    markers::first_marker(msg.as_bytes(), packet_size).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day06.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day06.part_1(&inp), Answer::UInt(7));
+        assert_eq!(Day06.part_2(&inp), Some(Answer::UInt(19)));
+    }
+
+    #[test]
+    fn test_get_marker_idx() {
+        let msg = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        assert_eq!(get_marker_idx(msg, 4), 7);
+        assert_eq!(get_marker_idx(msg, 14), 19);
+    }
+
+    #[test]
+    fn test_stream() {
+        for msg in [
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n",
+            "bvwbjplbgvbhsrlpgdmjqwftvncz",
+            "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg\r\n",
+        ] {
+            let inp = Day06.parse(msg).unwrap();
+            let (p1, p2) = Day06.stream(&mut msg.as_bytes()).unwrap().unwrap();
+            assert_eq!(p1, Day06.part_1(&inp));
+            assert_eq!(p2, Day06.part_2(&inp));
+        }
+
+        let all = all_markers(&mut "abcabcd\n".as_bytes(), &[3, 4, 3]).unwrap();
+        assert_eq!(all, vec![vec![3, 4, 5, 6, 7], vec![7], vec![3, 4, 5, 6, 7]]);
+
+        for (msg, err) in [
+            (
+                "abcabc\n",
+                "line 1, column 7: expected a start-of-packet marker (4 different letters in a row), found end of line",
+            ),
+            (
+                "abcdefghijklmabc",
+                "line 1, column 17: expected a start-of-message marker (14 different letters in a row), found end of line",
+            ),
+        ] {
+            assert_eq!(Day06.parse(msg).unwrap_err().to_string(), err);
+            let stream_err = Day06.stream(&mut msg.as_bytes()).unwrap().unwrap_err();
+            assert_eq!(stream_err.to_string(), err);
+        }
+
+        let err = Day06.stream(&mut "abcD".as_bytes()).unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 4: expected a lowercase letter, found `D`"
+        );
+        let err = Day06
+            .stream(&mut "abcd\nefgh".as_bytes())
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected end of input, found `e`"
+        );
+    }
+}
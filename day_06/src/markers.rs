@@ -0,0 +1,111 @@
+/// Looks for markers of several sizes at once: runs of that many different
+/// bytes. Takes the datastream a byte at a time, in O(1) per byte and size.
+pub struct Markers {
+    /// The last bytes, as many as the largest size.
+    ring: Vec<u8>,
+    len: usize,
+    windows: Vec<Window>,
+}
+
+struct Window {
+    size: usize,
+    counts: [u32; 256],
+    distinct: usize,
+}
+
+impl Markers {
+    /// Panics for a size of 0.
+    pub fn new(sizes: &[usize]) -> Self {
+        assert!(!sizes.contains(&0), "a marker can't be empty");
+        let windows = sizes
+            .iter()
+            .map(|&size| Window {
+                size,
+                counts: [0; 256],
+                distinct: 0,
+            })
+            .collect();
+        Markers {
+            ring: vec![0; sizes.iter().copied().max().unwrap_or(0)],
+            len: 0,
+            windows,
+        }
+    }
+
+    /// How many bytes were pushed.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, b: u8) {
+        for w in self.windows.iter_mut() {
+            w.counts[b as usize] += 1;
+            if w.counts[b as usize] == 1 {
+                w.distinct += 1;
+            }
+            // The byte that falls out, read before the ring overwrites it.
+            if self.len >= w.size {
+                let old = self.ring[(self.len - w.size) % self.ring.len()] as usize;
+                w.counts[old] -= 1;
+                if w.counts[old] == 0 {
+                    w.distinct -= 1;
+                }
+            }
+        }
+        if !self.ring.is_empty() {
+            let at = self.len % self.ring.len();
+            self.ring[at] = b;
+        }
+        self.len += 1;
+    }
+
+    /// The sizes for which the last bytes pushed are a marker, as indexes
+    /// into the ones given to `new`.
+    pub fn found(&self) -> impl Iterator<Item = usize> + '_ {
+        self.windows
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| w.distinct == w.size)
+            .map(|(i, _)| i)
+    }
+}
+
+/// Where the first marker of `size` ends, if there is one.
+pub fn first_marker(msg: &[u8], size: usize) -> Option<usize> {
+    let mut markers = Markers::new(&[size]);
+    msg.iter().find_map(|&b| {
+        markers.push(b);
+        markers.found().next().map(|_| markers.len())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markers() {
+        let mut markers = Markers::new(&[4, 1, 14]);
+        let mut found = Vec::new();
+        for b in b"mjqjpqmgbljsphdztnvjfqwrcgsmlb" {
+            markers.push(*b);
+            for i in markers.found().filter(|i| *i != 1) {
+                found.push(([4, 1, 14][i], markers.len()));
+            }
+        }
+        let at = |size| {
+            let at = found.iter().filter(|(s, _)| *s == size);
+            at.map(|(_, at)| *at).collect::<Vec<_>>()
+        };
+        assert_eq!(at(4), (7..=30).collect::<Vec<_>>());
+        assert_eq!(at(14), vec![19, 25, 26, 27, 28, 29, 30]);
+
+        assert_eq!(first_marker(b"bvwbjplbgvbhsrlpgdmjqwftvncz", 4), Some(5));
+        assert_eq!(first_marker(b"aaaa", 2), None);
+        assert_eq!(first_marker(b"abc", 4), None);
+    }
+}
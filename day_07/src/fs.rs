@@ -0,0 +1,184 @@
+/// Where a directory is in its `FileSystem`.
+pub type DirId = usize;
+
+#[derive(Debug)]
+pub struct File {
+    pub name: String,
+    pub size: u64,
+}
+
+#[derive(Debug)]
+pub struct Directory {
+    name: String,
+    parent: Option<DirId>,
+    subdirs: Vec<DirId>,
+    files: Vec<File>,
+    /// The size of its own files.
+    files_size: u64,
+    /// The size of everything in it, subdirectories included, as of the last
+    /// `sum_sizes`.
+    size: u64,
+}
+
+impl Directory {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// None for the root.
+    pub fn parent(&self) -> Option<DirId> {
+        self.parent
+    }
+
+    pub fn subdirs(&self) -> &[DirId] {
+        &self.subdirs
+    }
+
+    pub fn files(&self) -> &[File] {
+        &self.files
+    }
+
+    /// Only counts what was there at the last `FileSystem::sum_sizes`.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// A tree of directories, stored side by side. Every directory knows its
+/// parent and its total size, so neither takes a walk through the tree.
+/// Subdirectories come after their parent.
+#[derive(Debug)]
+pub struct FileSystem {
+    dirs: Vec<Directory>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        FileSystem::new()
+    }
+}
+
+impl FileSystem {
+    pub const ROOT: DirId = 0;
+
+    /// Just an empty root.
+    pub fn new() -> Self {
+        FileSystem {
+            dirs: vec![Directory {
+                name: "/".to_string(),
+                parent: None,
+                subdirs: Vec::new(),
+                files: Vec::new(),
+                files_size: 0,
+                size: 0,
+            }],
+        }
+    }
+
+    /// Panics for an id from another file system.
+    pub fn dir(&self, id: DirId) -> &Directory {
+        &self.dirs[id]
+    }
+
+    /// Every directory, the root first.
+    pub fn dirs(&self) -> impl Iterator<Item = &Directory> {
+        self.dirs.iter()
+    }
+
+    pub fn subdir(&self, id: DirId, name: &str) -> Option<DirId> {
+        let subdirs = &self.dirs[id].subdirs;
+        subdirs.iter().copied().find(|d| self.dirs[*d].name == name)
+    }
+
+    /// Like `/a/e`.
+    pub fn path(&self, id: DirId) -> String {
+        let mut names = Vec::new();
+        let mut at = id;
+        while let Some(parent) = self.dirs[at].parent {
+            names.push(self.dirs[at].name.as_str());
+            at = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// Adds a subdirectory, unless one by that name is already there.
+    pub fn add_dir(&mut self, parent: DirId, name: &str) -> DirId {
+        if let Some(id) = self.subdir(parent, name) {
+            return id;
+        }
+        let id = self.dirs.len();
+        self.dirs.push(Directory {
+            name: name.to_string(),
+            parent: Some(parent),
+            subdirs: Vec::new(),
+            files: Vec::new(),
+            files_size: 0,
+            size: 0,
+        });
+        self.dirs[parent].subdirs.push(id);
+        id
+    }
+
+    /// Adds a file, unless one by that name is already there. Its size
+    /// counts for the directory and everything above it after `sum_sizes`.
+    pub fn add_file(&mut self, dir: DirId, name: &str, size: u64) {
+        let dir = &mut self.dirs[dir];
+        if dir.files.iter().any(|f| f.name == name) {
+            return;
+        }
+        dir.files.push(File {
+            name: name.to_string(),
+            size,
+        });
+        dir.files_size += size;
+    }
+
+    /// Totals the size of every directory, in a single pass from the
+    /// deepest ones up.
+    pub fn sum_sizes(&mut self) {
+        for d in self.dirs.iter_mut() {
+            d.size = d.files_size;
+        }
+        for id in (1..self.dirs.len()).rev() {
+            let (size, parent) = (self.dirs[id].size, self.dirs[id].parent.unwrap());
+            self.dirs[parent].size += size;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fs() {
+        let mut fs = FileSystem::new();
+        let a = fs.add_dir(FileSystem::ROOT, "a");
+        let e = fs.add_dir(a, "e");
+        fs.add_file(e, "i", 584);
+        fs.add_file(a, "f", 29116);
+        fs.add_file(FileSystem::ROOT, "b.txt", 14848514);
+        // Listing a directory twice doesn't count it twice.
+        assert_eq!(fs.add_dir(FileSystem::ROOT, "a"), a);
+        fs.add_file(e, "i", 584);
+        fs.sum_sizes();
+
+        assert_eq!(fs.dir(e).size(), 584);
+        assert_eq!(fs.dir(a).size(), 29700);
+        assert_eq!(fs.dir(FileSystem::ROOT).size(), 14878214);
+        assert_eq!(fs.dir(e).parent(), Some(a));
+        assert_eq!(fs.dir(FileSystem::ROOT).parent(), None);
+        assert_eq!(fs.subdir(a, "e"), Some(e));
+        assert_eq!(fs.subdir(a, "x"), None);
+        assert_eq!(fs.path(e), "/a/e");
+        assert_eq!(fs.path(FileSystem::ROOT), "/");
+        assert_eq!(fs.dirs().count(), 3);
+
+        // Summing again doesn't count anything twice.
+        fs.add_file(e, "j", 16);
+        fs.sum_sizes();
+        assert_eq!(fs.dir(a).size(), 29716);
+        assert_eq!(fs.dir(FileSystem::ROOT).size(), 14878230);
+    }
+}
@@ -0,0 +1,144 @@
+use aoc_common::parse::Lines;
+use aoc_common::{parse_param, Answer, ParamError, ParseError, Solver};
+use fs::{DirId, FileSystem};
+
+pub mod fs;
+
+pub struct Day07 {
+    /// Size of the disk.
+    pub total_space: u64,
+    /// Free space the update needs.
+    pub needed_space: u64,
+}
+
+impl Default for Day07 {
+    fn default() -> Self {
+        Day07 {
+            total_space: 70000000,
+            needed_space: 30000000,
+        }
+    }
+}
+
+impl Solver for Day07 {
+    type Input = FileSystem;
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("total_space", self.total_space.to_string()),
+            ("needed_space", self.needed_space.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "total_space" => self.total_space = parse_param(name, value)?,
+            "needed_space" => self.needed_space = parse_param(name, value)?,
+            _ => return Err(ParamError::unknown(name, self.params())),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, fs: &Self::Input) -> Answer {
+        let p1: u64 = dir_sizes(fs).iter().filter(|size| **size <= 100000).sum();
+        p1.into()
+    }
+
+    fn part_2(&self, fs: &Self::Input) -> Option<Answer> {
+        let free_space = self
+            .total_space
+            .saturating_sub(fs.dir(FileSystem::ROOT).size());
+        let space_to_free = self.needed_space.saturating_sub(free_space);
+        // None when even deleting everything doesn't free enough.
+        dir_sizes(fs)
+            .into_iter()
+            .filter(|size| *size >= space_to_free)
+            .min()
+            .map(Answer::from)
+    }
+}
+
+fn parse_input(inp: &str) -> Result<FileSystem, ParseError> {
+    let mut fs = FileSystem::new();
+    let mut working_dir = FileSystem::ROOT;
+
+    for mut line in Lines::new(inp) {
+        if line.try_tag("$ ") {
+            if line.any_tag(&["cd ", "ls"])? == "cd " {
+                let at = line.clone();
+                working_dir = match line.token()? {
+                    "/" => Some(FileSystem::ROOT),
+                    ".." => fs.dir(working_dir).parent(),
+                    dirname => fs.subdir(working_dir, dirname),
+                }
+                .ok_or_else(|| at.error(cd_expected(&fs, working_dir)))?;
+            }
+        } else if line.try_tag("dir ") {
+            let name = line.token()?;
+            fs.add_dir(working_dir, name);
+        } else {
+            let size = line
+                .number::<u64>()
+                .map_err(|_| line.error("`$`, `dir` or a file size"))?;
+            line.tag(" ")?;
+            let name = line.token()?;
+            fs.add_file(working_dir, name, size);
+        }
+        line.end()?;
+    }
+
+    fs.sum_sizes();
+    Ok(fs)
+}
+
+/// Where `cd` can go from `dir`: only into directories that were listed.
+fn cd_expected(fs: &FileSystem, dir: DirId) -> String {
+    let up = match fs.dir(dir).parent() {
+        Some(_) => ", `..`",
+        None => "",
+    };
+    format!("`/`{} or a directory listed in {}", up, fs.path(dir))
+}
+
+/// The total size of every directory, the root too.
+fn dir_sizes(fs: &FileSystem) -> Vec<u64> {
+    fs.dirs().map(|d| d.size()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let day = Day07::default();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(day.part_1(&inp), Answer::UInt(95437));
+        assert_eq!(day.part_2(&inp), Some(Answer::UInt(24933642)));
+
+        let day = Day07 {
+            needed_space: 100000000,
+            ..Day07::default()
+        };
+        assert_eq!(day.part_2(&inp), None);
+    }
+
+    #[test]
+    fn test_cd_errors() {
+        let inp = "$ cd /\n$ ls\ndir a\n$ cd a\n$ cd b\n";
+        assert_eq!(
+            parse_input(inp).unwrap_err().to_string(),
+            "line 5, column 6: expected `/`, `..` or a directory listed in /a, found `b`"
+        );
+
+        let inp = "$ cd /\n$ ls\n10 b.txt\n$ cd ..\n";
+        assert_eq!(
+            parse_input(inp).unwrap_err().to_string(),
+            "line 4, column 6: expected `/` or a directory listed in /, found `..`"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use aoc_common::{trace, Answer, Grid, ParseError, Solver};
+use take_until::TakeUntilExt;
+
+#[derive(Default)]
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input = Grid<i8>;
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(inp, "a tree height (0-9)", |c| {
+            c.to_digit(10).map(|d| d as i8)
+        })
+    }
+
+    fn part_1(&self, height_map: &Self::Input) -> Answer {
+        let visibility = build_visibility_map(height_map);
+        visibility.values().filter(|b| **b).count().into()
+    }
+
+    fn part_2(&self, height_map: &Self::Input) -> Option<Answer> {
+        let scores = build_scenic_score_map(height_map);
+        let best = scores.values().max().unwrap();
+        Some((*best).into())
+    }
+}
+
+fn build_visibility_map(height_map: &Grid<i8>) -> Grid<bool> {
+    let mut visible = visible_from_sides(height_map);
+    // Looking from the top or bottom is looking from the sides of the transposed map.
+    let from_top = visible_from_sides(&height_map.transpose()).transpose();
+    for (pos, v) in from_top.iter() {
+        if *v {
+            visible[pos] = true;
+        }
+    }
+    visible
+}
+
+fn visible_from_sides(height_map: &Grid<i8>) -> Grid<bool> {
+    let mut visible = height_map.map(|_| false);
+
+    for (y, map_line) in height_map.rows().enumerate() {
+        let mut max_seen: i8 = -1;
+        for (x, height) in map_line.iter().enumerate() {
+            if *height > max_seen {
+                visible[(x, y)] = true;
+                max_seen = *height;
+            }
+            if *height == 9 {
+                break;
+            }
+        }
+
+        max_seen = -1;
+        for (x, height) in map_line.iter().enumerate().rev() {
+            if *height > max_seen {
+                visible[(x, y)] = true;
+                max_seen = *height;
+            }
+            if *height == 9 {
+                break;
+            }
+        }
+    }
+
+    visible
+}
+
+fn build_scenic_score_map(height_map: &Grid<i8>) -> Grid<u64> {
+    let mut scores = height_map.map(|_| 0);
+
+    for y in 1..height_map.height() - 1 {
+        let map_line = height_map.row(y);
+        for x in 1..height_map.width() - 1 {
+            let height = &map_line[x];
+
+            let vis_right = map_line[x + 1..]
+                .iter()
+                .take_until(|t| *t >= height)
+                .count();
+            let vis_left = map_line[..x]
+                .iter()
+                .rev()
+                .take_until(|t| *t >= height)
+                .count();
+            let vis_down = height_map
+                .column(x)
+                .skip(y + 1)
+                .take_until(|t| *t >= height)
+                .count();
+            let vis_up = height_map
+                .column(x)
+                .take(y)
+                .rev()
+                .take_until(|t| *t >= height)
+                .count();
+
+            trace!(
+                "{},{}: {}*{}*{}*{}",
+                y,
+                x,
+                vis_up,
+                vis_left,
+                vis_right,
+                vis_down
+            );
+            scores[(x, y)] = (vis_right * vis_left * vis_down * vis_up) as u64;
+        }
+    }
+
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_grid() -> Grid<i8> {
+        Grid::from_rows(
+            [
+                [3, 0, 3, 7, 3],
+                [2, 5, 5, 1, 2],
+                [6, 5, 3, 3, 2],
+                [3, 3, 5, 4, 9],
+                [3, 5, 3, 9, 0],
+            ]
+            .iter()
+            .map(|r| r.to_vec())
+            .collect(),
+        )
+    }
+
+    #[test]
+    fn test_example() {
+        let inp = Day08.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day08.part_1(&inp), Answer::UInt(21));
+        assert_eq!(Day08.part_2(&inp), Some(Answer::UInt(8)));
+    }
+
+    #[test]
+    fn test_build_visibility_map() {
+        let inp = example_grid();
+        let exp = Grid::from_rows(
+            [
+                [true, true, true, true, true],
+                [true, true, true, false, true],
+                [true, true, false, true, true],
+                [true, false, true, false, true],
+                [true, true, true, true, true],
+            ]
+            .iter()
+            .map(|r| r.to_vec())
+            .collect(),
+        );
+
+        let res = build_visibility_map(&inp);
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_build_score_map() {
+        let inp = example_grid();
+        let exp = Grid::from_rows(
+            [
+                [0, 0, 0, 0, 0],
+                [0, 1, 4, 1, 0],
+                [0, 6, 1, 2, 0],
+                [0, 1, 8, 3, 0],
+                [0, 0, 0, 0, 0],
+            ]
+            .iter()
+            .map(|r| r.to_vec())
+            .collect(),
+        );
+
+        let res = build_scenic_score_map(&inp);
+        assert_eq!(res, exp);
+    }
+}
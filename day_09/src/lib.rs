@@ -0,0 +1,176 @@
+use aoc_common::parse::{Lines, Scanner};
+use aoc_common::render::{self, Cell, Color, Frame};
+use aoc_common::{stream, Answer, ParseError, Solver, SparseGrid, StreamError};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+type Pos = (i64, i64);
+
+// (dx, dy, distance)
+type Motion = (i64, i64, i64);
+
+#[derive(Default)]
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input = Vec<Motion>;
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        Lines::new(inp)
+            .map(|mut line| parse_motion(&mut line))
+            .collect()
+    }
+
+    fn part_1(&self, motions: &Self::Input) -> Answer {
+        tail_visits(motions, 2).into()
+    }
+
+    fn part_2(&self, motions: &Self::Input) -> Option<Answer> {
+        Some(tail_visits(motions, 10).into())
+    }
+
+    fn stream(
+        &self,
+        input: &mut dyn BufRead,
+    ) -> Option<Result<(Answer, Option<Answer>), StreamError>> {
+        let (mut short, mut long) = (Rope::new(2), Rope::new(10));
+        let res = stream::lines(input, |mut line| {
+            let motion = parse_motion(&mut line)?;
+            short.apply(motion);
+            long.apply(motion);
+            Ok(())
+        });
+        Some(res.map(|_| (short.visited.len().into(), Some(long.visited.len().into()))))
+    }
+}
+
+fn parse_motion(line: &mut Scanner) -> Result<Motion, ParseError> {
+    let (dx, dy) = match line.one_of("UDLR")? {
+        'U' => (0, 1),
+        'D' => (0, -1),
+        'L' => (-1, 0),
+        _ => (1, 0),
+    };
+    line.tag(" ")?;
+    let dist = line.number::<i64>()?;
+    line.end()?;
+    Ok((dx, dy, dist))
+}
+
+struct Rope {
+    knots: Vec<Pos>,
+    // Everywhere the tail has been.
+    visited: HashSet<Pos>,
+}
+
+impl Rope {
+    fn new(n_knots: usize) -> Self {
+        Rope {
+            knots: vec![(0, 0); n_knots],
+            visited: HashSet::new(),
+        }
+    }
+
+    fn apply(&mut self, (dx, dy, dist): Motion) {
+        for _ in 0..dist {
+            self.knots[0].0 += dx;
+            self.knots[0].1 += dy;
+
+            for idx in 1..self.knots.len() {
+                let head = self.knots[idx - 1];
+                let tail = &mut self.knots[idx];
+                follow(&head, tail);
+            }
+            self.visited.insert(*self.knots.last().unwrap());
+        }
+    }
+}
+
+fn tail_visits(motions: &[Motion], n_knots: usize) -> usize {
+    let mut rope = Rope::new(n_knots);
+    for motion in motions {
+        rope.apply(*motion);
+        render::record(|| draw(&rope.knots, &rope.visited));
+    }
+    rope.visited.len()
+}
+
+fn follow(head: &Pos, tail: &mut Pos) {
+    match (head.0 - tail.0, head.1 - tail.1) {
+        // Explicitly do nothing, so the catch-all can report
+        // on the truly weird cases.
+        (x, y) if (-1..=1).contains(&x) && (-1..=1).contains(&y) => (),
+        // Right
+        (x, y) if x > 1 && y == 0 => tail.0 += 1,
+        // Left
+        (x, y) if x < -1 && y == 0 => tail.0 -= 1,
+        // Up
+        (x, y) if x == 0 && y > 1 => tail.1 += 1,
+        // Down
+        (x, y) if x == 0 && y < -1 => tail.1 -= 1,
+        // Up+Right
+        (x, y) if (x > 1 && y >= 1) || (x == 1 && y > 1) => {
+            tail.0 += 1;
+            tail.1 += 1;
+        }
+        // Down+Right
+        (x, y) if (x > 1 && y <= -1) || (x == 1 && y < -1) => {
+            tail.0 += 1;
+            tail.1 -= 1
+        }
+        // Up+Left
+        (x, y) if (x < -1 && y >= 1) || (x == -1 && y > 1) => {
+            tail.0 -= 1;
+            tail.1 += 1;
+        }
+        // Down+Left
+        (x, y) if (x < -1 && y <= -1) || (x == -1 && y < -1) => {
+            tail.0 -= 1;
+            tail.1 -= 1;
+        }
+        _ => panic!("Not sure how to follow from {:?} to {:?}", head, tail),
+    }
+}
+
+fn draw(knots: &[Pos], visited: &HashSet<Pos>) -> Frame {
+    // Up is positive y here, but down on screen.
+    let mut grid = SparseGrid::new();
+    for (x, y) in visited {
+        grid.insert((*x, -y), Cell::new('#', Color::BLUE));
+    }
+    // Draw the head last, so it ends up on top.
+    for (i, (x, y)) in knots.iter().enumerate().rev() {
+        let cell = match i {
+            0 => Cell::new('H', Color::RED),
+            _ => Cell::new(char::from_digit(i as u32 % 10, 10).unwrap(), Color::YELLOW),
+        };
+        grid.insert((*x, -y), cell);
+    }
+    Frame::from_sparse(&grid, Cell::new('.', Color::GREY), |c| *c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day09.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day09.part_1(&inp), Answer::UInt(13));
+        assert_eq!(Day09.part_2(&inp), Some(Answer::UInt(1)));
+    }
+
+    #[test]
+    fn test_larger_example() {
+        let inp = Day09.parse(include_str!("../example_2.txt")).unwrap();
+        assert_eq!(Day09.part_2(&inp), Some(Answer::UInt(36)));
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut inp = include_str!("../example_2.txt").as_bytes();
+        let (p1, p2) = Day09.stream(&mut inp).unwrap().unwrap();
+        assert_eq!(p1, Answer::UInt(88));
+        assert_eq!(p2, Some(Answer::UInt(36)));
+    }
+}
@@ -0,0 +1,172 @@
+use aoc_common::parse::{Lines, Scanner};
+use aoc_common::render::{self, Cell, Color, Frame};
+use aoc_common::{stream, Answer, Grid, ParseError, Solver, StreamError};
+use std::io::BufRead;
+
+#[derive(Debug, Clone)]
+pub enum Instr {
+    Noop,
+    AddX(i64),
+}
+
+#[derive(Default)]
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Vec<Instr>;
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        Lines::new(inp)
+            .map(|mut instr| parse_instr(&mut instr))
+            .collect()
+    }
+
+    fn part_1(&self, program: &Self::Input) -> Answer {
+        let (signal_strength, _) = execute(program);
+        signal_strength.into()
+    }
+
+    fn part_2(&self, program: &Self::Input) -> Option<Answer> {
+        let (_, crt) = execute(program);
+        Some(crt.into())
+    }
+
+    fn stream(
+        &self,
+        input: &mut dyn BufRead,
+    ) -> Option<Result<(Answer, Option<Answer>), StreamError>> {
+        let mut cpu = Cpu::new();
+        let res = stream::lines(input, |mut instr| {
+            cpu.run(&parse_instr(&mut instr)?);
+            Ok(())
+        });
+        Some(res.map(|_| {
+            let (signal_strength, crt) = cpu.finish();
+            (signal_strength.into(), Some(crt.into()))
+        }))
+    }
+}
+
+fn parse_instr(instr: &mut Scanner) -> Result<Instr, ParseError> {
+    let res = match instr.any_tag(&["noop", "addx "])? {
+        "noop" => Instr::Noop,
+        _ => Instr::AddX(instr.number()?),
+    };
+    instr.end()?;
+    Ok(res)
+}
+
+/// The CRT is 40 pixels wide and 6 high, and stops drawing once it's full.
+const CRT_PIXELS: i64 = 40 * 6;
+
+struct Cpu {
+    x: i64,
+    cycle: i64,
+    signal_strength: i64,
+    crt: String,
+}
+
+impl Cpu {
+    fn new() -> Self {
+        Cpu {
+            x: 1,
+            cycle: 1,
+            signal_strength: 0,
+            crt: String::new(),
+        }
+    }
+
+    fn tick(&mut self) {
+        if (self.cycle + 20) % 40 == 0 {
+            self.signal_strength += self.cycle * self.x;
+        }
+        if self.cycle <= CRT_PIXELS {
+            let pix = (self.cycle - 1) % 40;
+            match pix >= self.x - 1 && pix <= self.x + 1 {
+                true => self.crt.push('#'),
+                false => self.crt.push('.'),
+            }
+            if self.cycle % 40 == 0 {
+                self.crt.push('\n');
+            }
+            render::record(|| draw_crt(&self.crt));
+        }
+        self.cycle += 1;
+    }
+
+    fn run(&mut self, instr: &Instr) {
+        match instr {
+            Instr::Noop => self.tick(),
+            Instr::AddX(add) => {
+                self.tick();
+                self.tick();
+                self.x += add;
+            }
+        }
+    }
+
+    /// Returns the summed signal strength and the CRT image.
+    fn finish(mut self) -> (i64, String) {
+        // Unless it's full, the CRT draws one more pixel after the program
+        // ended. Drop that line, or the newline after the last one.
+        self.tick();
+        self.crt.truncate(self.crt.rfind('\n').unwrap_or(0));
+        (self.signal_strength, self.crt)
+    }
+}
+
+/// Runs the program, returning the summed signal strength and the CRT image.
+fn execute(program: &[Instr]) -> (i64, String) {
+    let mut cpu = Cpu::new();
+    for instr in program {
+        cpu.run(instr);
+    }
+    cpu.finish()
+}
+
+fn draw_crt(crt: &str) -> Frame {
+    let mut screen = Grid::new(40, 6, Cell::EMPTY);
+    for (y, line) in crt.lines().take(screen.height()).enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            screen[(x, y)] = match c {
+                '#' => Cell::new('#', Color::GREEN),
+                _ => Cell::new('.', Color::GREY),
+            };
+        }
+    }
+    Frame::new(screen)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day10.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day10.part_1(&inp), Answer::Int(13140));
+        let crt = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        assert_eq!(Day10.part_2(&inp), Some(Answer::Text(crt.to_string())));
+    }
+
+    #[test]
+    fn test_stream() {
+        let inp = include_str!("../example.txt");
+        let (p1, p2) = Day10.stream(&mut inp.as_bytes()).unwrap().unwrap();
+        let program = Day10.parse(inp).unwrap();
+        assert_eq!(p1, Day10.part_1(&program));
+        assert_eq!(p2, Day10.part_2(&program));
+
+        // A long program doesn't draw past the bottom of the screen.
+        let inp = "noop\n".repeat(1000);
+        let (_, p2) = Day10.stream(&mut inp.as_bytes()).unwrap().unwrap();
+        let screen = vec!["###".to_string() + &".".repeat(37); 6].join("\n");
+        assert_eq!(p2, Some(Answer::Text(screen)));
+    }
+}
@@ -0,0 +1,205 @@
+use aoc_common::parse::Lines;
+use aoc_common::{parse_param, Answer, ParamError, ParseError, Solver};
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: VecDeque<i64>,
+    op: Rc<dyn Fn(i64) -> i64>,
+    reducer: Rc<dyn Fn(i64) -> i64>,
+    test_modul: i64,
+    target_if_true: usize,
+    target_if_false: usize,
+    n_inspects: u64,
+}
+
+impl Monkey {
+    fn inspect(&mut self) -> Option<i64> {
+        let mut worry = self.items.pop_front()?;
+        worry = (self.op)(worry);
+        worry = (self.reducer)(worry);
+        self.n_inspects += 1;
+        Some(worry)
+    }
+}
+
+pub struct Day11 {
+    /// Rounds to play in part 1.
+    pub p1_rounds: usize,
+    /// Rounds to play in part 2, without worry relief.
+    pub p2_rounds: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Day11 {
+            p1_rounds: 20,
+            p2_rounds: 10_000,
+        }
+    }
+}
+
+impl Solver for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("p1_rounds", self.p1_rounds.to_string()),
+            ("p2_rounds", self.p2_rounds.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "p1_rounds" => self.p1_rounds = parse_param(name, value)?,
+            "p2_rounds" => self.p2_rounds = parse_param(name, value)?,
+            _ => return Err(ParamError::unknown(name, self.params())),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input_monkeys(inp)
+    }
+
+    fn part_1(&self, monkeys: &Self::Input) -> Answer {
+        let mut monkeys = monkeys.clone();
+        solve(&mut monkeys, self.p1_rounds).into()
+    }
+
+    fn part_2(&self, monkeys: &Self::Input) -> Option<Answer> {
+        let mut monkeys_2 = monkeys.clone();
+
+        let common_factor: i64 = monkeys_2.iter().map(|m| m.test_modul).product();
+        let reducer = Rc::new(move |x| x % common_factor);
+        for m in &mut monkeys_2 {
+            m.reducer = reducer.clone();
+        }
+
+        Some(solve(&mut monkeys_2, self.p2_rounds).into())
+    }
+}
+
+fn solve(monkeys: &mut [Monkey], rounds: usize) -> u64 {
+    for _round in 0..rounds {
+        for i in 0..monkeys.len() {
+            loop {
+                let monk = &mut monkeys[i];
+                let item = match monk.inspect() {
+                    Some(i) => i,
+                    None => break,
+                };
+                let target = match item % monk.test_modul {
+                    0 => monk.target_if_true,
+                    _ => monk.target_if_false,
+                };
+                monkeys[target].items.push_back(item);
+            }
+        }
+    }
+
+    monkeys.sort_by_key(|m| m.n_inspects);
+    let monkey_business: u64 = monkeys.iter().rev().take(2).map(|m| m.n_inspects).product();
+    monkey_business
+}
+
+fn parse_input_monkeys(inp: &str) -> Result<Vec<Monkey>, ParseError> {
+    Lines::blocks(inp).into_iter().map(parse_monkey).collect()
+}
+
+fn parse_monkey(mut lines: Lines) -> Result<Monkey, ParseError> {
+    let mut line = lines.expect("`Monkey`")?;
+    line.tag("Monkey ")?;
+    line.number::<usize>()?;
+    line.tag(":")?;
+    line.end()?;
+
+    let mut line = lines.expect("the starting items")?;
+    line.skip_whitespace();
+    line.tag("Starting items:")?;
+    let items: VecDeque<i64> = match line.is_empty() {
+        true => VecDeque::new(),
+        false => {
+            line.tag(" ")?;
+            line.list(", ", |l| l.number())?.into()
+        }
+    };
+    line.end()?;
+
+    let mut line = lines.expect("the operation")?;
+    line.skip_whitespace();
+    line.tag("Operation: new = old ")?;
+    let operator = line.one_of("*+")?;
+    line.tag(" ")?;
+    let op: Rc<dyn Fn(i64) -> i64> = match (operator, line.try_tag("old")) {
+        ('*', true) => Rc::new(|v| v * v),
+        ('+', true) => Rc::new(|v| v + v),
+        ('*', false) => {
+            let mult = line.number::<i64>()?;
+            Rc::new(move |v| v * mult)
+        }
+        _ => {
+            let add = line.number::<i64>()?;
+            Rc::new(move |v| v + add)
+        }
+    };
+    line.end()?;
+
+    let mut line = lines.expect("the test")?;
+    line.skip_whitespace();
+    line.tag("Test: divisible by ")?;
+    let test_modul = line.number::<i64>()?;
+    line.end()?;
+
+    let mut line = lines.expect("the target if true")?;
+    line.skip_whitespace();
+    line.tag("If true: throw to monkey ")?;
+    let target_if_true = line.number::<usize>()?;
+    line.end()?;
+
+    let mut line = lines.expect("the target if false")?;
+    line.skip_whitespace();
+    line.tag("If false: throw to monkey ")?;
+    let target_if_false = line.number::<usize>()?;
+    line.end()?;
+    lines.end()?;
+
+    Ok(Monkey {
+        items,
+        op,
+        reducer: Rc::new(|x| x / 3),
+        test_modul,
+        target_if_true,
+        target_if_false,
+        n_inspects: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let day = Day11::default();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(day.part_1(&inp), Answer::UInt(10605));
+        assert_eq!(day.part_2(&inp), Some(Answer::UInt(2713310158)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let inp = "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old ^ 19\n";
+        assert_eq!(
+            parse_input_monkeys(inp).err().unwrap().to_string(),
+            "line 3, column 24: expected `*` or `+`, found `^`"
+        );
+
+        let inp = "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19\n";
+        assert_eq!(
+            parse_input_monkeys(inp).err().unwrap().to_string(),
+            "line 4, column 1: expected the test, found end of input"
+        );
+    }
+}
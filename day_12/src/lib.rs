@@ -0,0 +1,100 @@
+use aoc_common::grid::Pos;
+use aoc_common::search::{self, Path};
+use aoc_common::{debug, Answer, Grid, ParseError, Solver};
+
+#[derive(Default)]
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = HeightMap;
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, (height_map, path, _): &Self::Input) -> Answer {
+        debug!("{}", draw_path(height_map, path));
+        path.cost.into()
+    }
+
+    fn part_2(&self, (height_map, _, end): &Self::Input) -> Option<Answer> {
+        // Start from all of the lowest points at once:
+        let starts = height_map
+            .iter()
+            .filter(|(_, h)| **h == 0)
+            .map(|(pos, _)| pos);
+        shortest_path(height_map, starts, *end).map(|path| path.cost.into())
+    }
+}
+
+/// The heights, the shortest path from `S`, and `E`.
+type HeightMap = (Grid<i8>, Path<Pos, usize>, Pos);
+
+/// Also finds the path from `S` to `E`, to make sure there is one.
+fn parse_input(inp: &str) -> Result<HeightMap, ParseError> {
+    let chars = Grid::parse(inp, "a height (a-z), `S` or `E`", |c| {
+        (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+    })?;
+    let start = chars
+        .position(|c| *c == 'S')
+        .ok_or_else(|| ParseError::at_end(inp, "a start position `S`"))?;
+    let end = chars
+        .position(|c| *c == 'E')
+        .ok_or_else(|| ParseError::at_end(inp, "a best signal position `E`"))?;
+    let height_map = chars.map(|ch| match ch {
+        'S' => 0,
+        'E' => 25,
+        x => (*x as u32 - 'a' as u32) as i8,
+    });
+    let Some(path) = shortest_path(&height_map, [start], end) else {
+        let expected = "a best signal position `E` that can be reached from `S`";
+        return Err(ParseError::new(end.1 + 1, end.0 + 1, expected, "`E`"));
+    };
+    Ok((height_map, path, end))
+}
+
+fn shortest_path(
+    height_map: &Grid<i8>,
+    starts: impl IntoIterator<Item = Pos>,
+    end: Pos,
+) -> Option<Path<Pos, usize>> {
+    search::bfs(
+        starts,
+        |&pos| {
+            // We can climb at most one step up:
+            let max_height = height_map[pos] + 1;
+            height_map
+                .neighbors4(pos)
+                .filter(move |n| height_map[*n] <= max_height)
+        },
+        |pos| *pos == end,
+    )
+}
+
+fn draw_path(height_map: &Grid<i8>, path: &Path<Pos, usize>) -> Grid<char> {
+    let mut map = height_map.map(|h| (b'a' + *h as u8) as char);
+    for pos in &path.nodes {
+        map[*pos] = '#';
+    }
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day12.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day12.part_1(&inp), Answer::UInt(31));
+        assert_eq!(Day12.part_2(&inp), Some(Answer::UInt(29)));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        assert_eq!(
+            Day12.parse("Sbz\nabE\n").unwrap_err().to_string(),
+            "line 2, column 3: expected a best signal position `E` that can be reached from `S`, found `E`"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use aoc_common::parse::{Lines, Scanner};
+use aoc_common::{Answer, ParseError, Solver};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[derive(Default)]
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Vec<Vec<Packet>>;
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, pairs: &Self::Input) -> Answer {
+        let p1: usize = pairs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, pkts)| match pkts[0] < pkts[1] {
+                true => Some(i + 1),
+                false => None,
+            })
+            .sum();
+        p1.into()
+    }
+
+    fn part_2(&self, pairs: &Self::Input) -> Option<Answer> {
+        let dividers: Vec<Packet> = ["[[2]]", "[[6]]"]
+            .iter()
+            .map(|p| p.parse().unwrap())
+            .collect();
+        let mut inp_p2: Vec<Packet> = pairs.iter().flatten().cloned().collect();
+        inp_p2.extend_from_slice(&dividers[..]);
+        inp_p2.sort_unstable();
+
+        let p2: usize = inp_p2
+            .iter()
+            .enumerate()
+            .filter_map(|(i, pkt)| match dividers.contains(pkt) {
+                true => Some(i + 1),
+                false => None,
+            })
+            .product();
+        Some(p2.into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Val {
+    Int(u32),
+    List(Vec<Val>),
+}
+
+fn _cmp_lists(a: &[Val], b: &[Val]) -> Ordering {
+    for (l, r) in a.iter().zip(b.iter()) {
+        match l.cmp(r) {
+            Ordering::Equal => continue,
+            o => return o,
+        };
+    }
+    // No clear ordering from items in list, compare lengths:
+    a.len().cmp(&b.len())
+}
+
+impl Ord for Val {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Val::Int(l), Val::Int(r)) => l.cmp(r),
+            (Val::List(l), Val::List(r)) => _cmp_lists(l, r),
+            (Val::List(l), Val::Int(r)) => _cmp_lists(l, &[Val::Int(*r)]),
+            (Val::Int(l), Val::List(r)) => _cmp_lists(&[Val::Int(*l)], r),
+        }
+    }
+}
+
+impl PartialOrd for Val {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    data: Vec<Val>,
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_packet(&mut Scanner::new(1, s))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        _cmp_lists(&self.data, &other.data)
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_input(inp: &str) -> Result<Vec<Vec<Packet>>, ParseError> {
+    Lines::blocks(inp)
+        .into_iter()
+        .map(|mut pair| {
+            let left = parse_packet(&mut pair.expect("a packet")?)?;
+            let right = parse_packet(&mut pair.expect("a packet")?)?;
+            pair.end()?;
+            Ok(vec![left, right])
+        })
+        .collect()
+}
+
+fn parse_packet(line: &mut Scanner) -> Result<Packet, ParseError> {
+    if line.peek() != Some('[') {
+        return Err(line.error("`[`"));
+    }
+    let packet = match parse_val(line)? {
+        Val::List(v) => Packet { data: v },
+        Val::Int(_) => unreachable!(),
+    };
+    line.end()?;
+    Ok(packet)
+}
+
+fn parse_val(line: &mut Scanner) -> Result<Val, ParseError> {
+    if !line.try_tag("[") {
+        return Ok(Val::Int(line.number()?));
+    }
+    let mut list = Vec::new();
+    if line.try_tag("]") {
+        return Ok(Val::List(list));
+    }
+    loop {
+        list.push(parse_val(line)?);
+        match line.one_of(",]")? {
+            ',' => continue,
+            _ => return Ok(Val::List(list)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day13.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day13.part_1(&inp), Answer::UInt(13));
+        assert_eq!(Day13.part_2(&inp), Some(Answer::UInt(140)));
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Val::Int(1) == Val::Int(1));
+        assert!(Val::Int(2) > Val::Int(1));
+        assert!(Val::Int(1) < Val::Int(2));
+
+        assert!(Val::List(vec![]) == Val::List(vec![]));
+        assert!(Val::List(vec![]) < Val::List(vec![Val::Int(1)]));
+        assert!(Val::List(vec![Val::Int(1)]) > Val::List(vec![]));
+
+        assert!(Val::List(vec![Val::Int(1)]) == Val::List(vec![Val::Int(1)]));
+        assert!(Val::List(vec![Val::Int(1)]) < Val::List(vec![Val::Int(2)]));
+        assert!(Val::List(vec![Val::Int(2)]) > Val::List(vec![Val::Int(1)]));
+
+        assert!(
+            Val::List(vec![Val::List(vec![]), Val::Int(1)])
+                < Val::List(vec![Val::List(vec![]), Val::Int(2)])
+        );
+        assert!(
+            Val::List(vec![Val::List(vec![Val::Int(0)]), Val::Int(1)])
+                < Val::List(vec![Val::List(vec![Val::Int(0)]), Val::Int(2)])
+        );
+
+        assert!(Val::Int(1) < Val::List(vec![Val::Int(1), Val::Int(2)]));
+        assert!(
+            Val::List(vec![Val::List(vec![]), Val::Int(7)])
+                < Val::List(vec![Val::List(vec![Val::Int(3)])])
+        )
+    }
+}
@@ -0,0 +1,155 @@
+use aoc_common::grid::Pos;
+use aoc_common::parse::Lines;
+use aoc_common::render::{self, Cell, Color, Frame};
+use aoc_common::{Answer, Grid, ParseError, Solver};
+use std::cmp;
+
+#[derive(Default)]
+pub struct Day14;
+
+impl Solver for Day14 {
+    // The map and the offset of its top left corner.
+    type Input = (Grid<char>, Pos);
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, (map, offset): &Self::Input) -> Answer {
+        let mut map_p1 = map.clone();
+
+        while let Some(settled_at) = drop_sand_abyss(&map_p1, (500 - offset.0, 0 - offset.1)) {
+            map_p1[settled_at] = 'o';
+            render::record(|| draw(&map_p1));
+        }
+        let p1 = map_p1.values().filter(|c| **c == 'o').count();
+        p1.into()
+    }
+
+    fn part_2(&self, (map, offset): &Self::Input) -> Option<Answer> {
+        // Extend the map horizontally to account for new rules, and add the floor.
+        const EXTEND_BY: usize = 200;
+        let mut map_p2 = Grid::new(map.width() + 2 * EXTEND_BY, map.height() + 2, '.');
+        for ((x, y), c) in map.iter() {
+            map_p2[(x + EXTEND_BY, y)] = *c;
+        }
+        let floor = map_p2.height() - 1;
+        map_p2.row_mut(floor).fill('#');
+        let offset_2 = (offset.0 - EXTEND_BY, offset.1);
+
+        let start = (500 - offset_2.0, 0 - offset_2.1);
+        while let Some(settled_at) = drop_sand_abyss(&map_p2, start) {
+            map_p2[settled_at] = 'o';
+            render::record(|| draw(&map_p2));
+            if settled_at == start {
+                break;
+            }
+        }
+        let p2 = map_p2.values().filter(|c| **c == 'o').count();
+        Some(p2.into())
+    }
+}
+
+fn parse_input(inp: &str) -> Result<(Grid<char>, Pos), ParseError> {
+    // First, find all points to determine the size and offset of this map.
+    let points: Vec<Vec<Pos>> = Lines::new(inp)
+        .map(|mut l| {
+            let mut prev: Option<Pos> = None;
+            let path = l.list(" -> ", |l| {
+                let column = l.column();
+                let x = l.number()?;
+                l.tag(",")?;
+                let y = l.number()?;
+                // Rock paths only run horizontally or vertically.
+                if let Some(p) = prev.filter(|p| p.0 != x && p.1 != y) {
+                    return Err(ParseError::new(
+                        l.line(),
+                        column,
+                        format!("a point in line with {},{}", p.0, p.1),
+                        format!("`{},{}`", x, y),
+                    ));
+                }
+                prev = Some((x, y));
+                Ok((x, y))
+            })?;
+            l.end()?;
+            Ok(path)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let bottom_left = points
+        .iter()
+        .flatten()
+        .fold((500, 0), |acc, pt| (cmp::min(acc.0, pt.0), 0));
+    let top_right = points.iter().flatten().fold((500, 0), |acc, pt| {
+        (cmp::max(acc.0, pt.0), cmp::max(acc.1, pt.1))
+    });
+    let width = top_right.0 - bottom_left.0 + 1;
+    let height = top_right.1 - bottom_left.1 + 1;
+
+    let mut map = Grid::new(width, height, '.');
+
+    for line in points {
+        for w in line.windows(2) {
+            let (x1, x2, y1, y2) = (
+                w[0].0 - bottom_left.0,
+                w[1].0 - bottom_left.0,
+                w[0].1,
+                w[1].1,
+            );
+            if x1 < x2 {
+                map.row_mut(y1)[x1..=x2].fill('#');
+            } else if x2 < x1 {
+                map.row_mut(y1)[x2..=x1].fill('#');
+            } else if y1 < y2 {
+                (y1..=y2).for_each(|y| map[(x1, y)] = '#');
+            } else if y2 < y1 {
+                (y2..=y1).for_each(|y| map[(x1, y)] = '#');
+            } else {
+                map[(x1, y1)] = '#';
+            }
+        }
+    }
+
+    Ok((map, bottom_left))
+}
+
+fn drop_sand_abyss(map: &Grid<char>, source: Pos) -> Option<Pos> {
+    let mut pos = source;
+
+    loop {
+        let next_y = pos.1 + 1;
+        // Reached the bottom, or the sides beyond which there's nothing to land on!
+        if next_y >= map.height() || pos.0 == 0 || pos.0 == map.width() - 1 {
+            return None;
+        }
+        let next_line = map.row(next_y);
+        match next_line[pos.0 - 1..=pos.0 + 1] {
+            [_, '.', _] => (),         // Straight down
+            ['.', _, _] => pos.0 -= 1, // Left
+            [_, _, '.'] => pos.0 += 1, // Right
+            _ => return Some(pos),     // Settled
+        }
+        pos.1 += 1;
+    }
+}
+
+fn draw(map: &Grid<char>) -> Frame {
+    Frame::from_grid(map, |c| match c {
+        '#' => Cell::new('#', Color::BROWN),
+        'o' => Cell::new('o', Color::YELLOW),
+        _ => Cell::new('.', Color::GREY),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day14.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day14.part_1(&inp), Answer::UInt(24));
+        assert_eq!(Day14.part_2(&inp), Some(Answer::UInt(93)));
+    }
+}
@@ -0,0 +1,82 @@
+use rand::prelude::*;
+
+type Pos = (i64, i64);
+
+fn dist(a: Pos, b: Pos) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+/// Whether a sensor at `pos` with beacon `b` keeps every sensor's beacon its
+/// closest one, its own included.
+fn fits(sensors: &[(Pos, Pos)], pos: Pos, b: Pos) -> bool {
+    let range = dist(pos, b);
+    sensors
+        .iter()
+        .all(|(s, sb)| *sb == b || (dist(pos, *sb) > range && dist(*s, b) > dist(*s, *sb)))
+}
+
+/// `size` sensors that leave exactly one position uncovered in `0..=limit`.
+/// A small `limit` leaves little room for noise, so it can come out with as
+/// few as 4.
+pub fn input(seed: u64, size: usize, limit: i64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let beacon = (rng.gen_range(0..=limit), rng.gen_range(0..=limit));
+
+    // One sensor per quadrant around the distress beacon covers that whole
+    // quadrant except the beacon itself. A sensor at (a, a) from the beacon,
+    // with a range of 2a-1, covers all (x, y) with x + y <= 4a-1 and
+    // max(x, y) <= 2a-1.
+    let mut sensors = Vec::new();
+    for (sx, sy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        let max_x = match sx {
+            1 => limit - beacon.0,
+            _ => beacon.0,
+        };
+        let max_y = match sy {
+            1 => limit - beacon.1,
+            _ => beacon.1,
+        };
+        let a = ((max_x + max_y + 1) / 4 + 1).max((max_x.max(max_y) + 1) / 2 + 1);
+        let pos = (beacon.0 + sx * a, beacon.1 + sy * a);
+        // The closest beacon is right next to the distress beacon.
+        sensors.push((pos, (beacon.0 + sx, beacon.1)));
+    }
+
+    // The rest is noise, that never reaches the distress beacon. Every
+    // sensor's beacon has to stay its closest one, so each try is checked
+    // against all other sensors and beacons.
+    for _ in 0..100 * size {
+        if sensors.len() >= size {
+            break;
+        }
+        let pos = (rng.gen_range(0..=limit), rng.gen_range(0..=limit));
+        if pos.0 == beacon.0 {
+            continue;
+        }
+        let mut tries: Vec<Pos> = (0..20)
+            .map(|_| {
+                let range = rng.gen_range(0..dist(pos, beacon));
+                let dx = rng.gen_range(-range..=range);
+                let dy = (range - dx.abs()) * [1, -1][rng.gen_range(0..2)];
+                (pos.0 + dx, pos.1 + dy)
+            })
+            .collect();
+        // The beacon next to the distress beacon, on this sensor's side, is
+        // always closer to it than the distress beacon.
+        tries.push((beacon.0 + (pos.0 - beacon.0).signum(), beacon.1));
+        if let Some(b) = tries.into_iter().find(|b| fits(&sensors, pos, *b)) {
+            sensors.push((pos, b));
+        }
+    }
+    sensors.shuffle(&mut rng);
+
+    sensors
+        .iter()
+        .map(|(s, b)| {
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                s.0, s.1, b.0, b.1
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,382 @@
+use aoc_common::parse::Lines;
+use aoc_common::{parse_param, Answer, ParamError, ParseError, Solver};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+mod generate;
+
+type Pos = (i64, i64);
+
+#[derive(Debug)]
+pub struct Sensor {
+    pos: Pos,
+    range: i64,
+}
+
+impl Sensor {
+    fn coverage_at_y(&self, y: i64) -> Option<RangeInclusive<i64>> {
+        let dy = (self.pos.1 - y).abs();
+        let half_width_at_y = self.range - dy;
+        match half_width_at_y {
+            w if w >= 0 => Some((self.pos.0 - half_width_at_y)..=(self.pos.0 + half_width_at_y)),
+            _ => None,
+        }
+    }
+
+    fn covers(&self, pt: Pos) -> bool {
+        let dist = (self.pos.0 - pt.0).abs() + (self.pos.1 - pt.1).abs();
+        dist <= self.range
+    }
+
+    fn boundary(&self) -> Vec<Pos> {
+        let mut res = Vec::new();
+        for x in (self.pos.0 - self.range - 1)..=(self.pos.0 + self.range + 1) {
+            let xdist = (self.pos.0 - x).abs();
+            let offset = self.range + 1 - xdist;
+            res.push((x, self.pos.1 + offset));
+            if offset > 0 {
+                res.push((x, self.pos.1 - offset));
+            }
+        }
+        res
+    }
+}
+
+pub struct Day15 {
+    /// The row to count beacon-free positions in for part 1.
+    pub row: i64,
+    /// The distress beacon has both coordinates in `0..=limit`.
+    pub limit: i64,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Day15 {
+            row: 2000000,
+            limit: 4000000,
+        }
+    }
+}
+
+impl Solver for Day15 {
+    type Input = (Vec<Sensor>, Vec<Pos>);
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("row", self.row.to_string()),
+            ("limit", self.limit.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "row" => self.row = parse_param(name, value)?,
+            "limit" => match parse_param(name, value)? {
+                n if n < 0 => {
+                    return Err(ParamError::Invalid {
+                        name: name.to_string(),
+                        value: value.to_string(),
+                    })
+                }
+                n => self.limit = n,
+            },
+            _ => return Err(ParamError::unknown(name, self.params())),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, (sensors, beacons): &Self::Input) -> Answer {
+        no_beacon_count(sensors, beacons, self.row).into()
+    }
+
+    fn part_2(&self, (sensors, _): &Self::Input) -> Option<Answer> {
+        tuning_frequency(sensors, self.limit).map(Answer::from)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        Some(generate::input(seed, size, self.limit))
+    }
+}
+
+/// How many positions in `row` can't contain a beacon.
+fn no_beacon_count(sensors: &[Sensor], beacons: &[Pos], row: i64) -> usize {
+    let mut covered = line_coverage(sensors, row);
+    // Don't count beacons!
+    beacons
+        .iter()
+        .filter(|(_x, y)| *y == row)
+        .for_each(|(x, _y)| {
+            covered.remove(x);
+        });
+    covered.len()
+}
+
+/// Finds the only uncovered position with both coordinates in `0..=limit`,
+/// if the sensors left one.
+fn tuning_frequency(sensors: &[Sensor], limit: i64) -> Option<i64> {
+    // The frequency always uses this multiplier, whatever the search limit.
+    const X_MULTIPLIER: i64 = 4000000;
+
+    // Check all boundary points within the limits. The beacon is always next
+    // to a covered position, unless the search area has just one position.
+    let boundary_points: Vec<Pos> = sensors
+        .iter()
+        .flat_map(|s| s.boundary())
+        .chain([(0, 0)])
+        .collect();
+    let distress_beacon = boundary_points
+        .iter()
+        .filter(|(x, y)| *x >= 0 && *x <= limit && *y >= 0 && *y <= limit)
+        .find(|pt| sensors.iter().all(|s| !s.covers(**pt)))?;
+    Some(distress_beacon.0 * X_MULTIPLIER + distress_beacon.1)
+}
+
+fn line_coverage(sensors: &[Sensor], line: i64) -> HashSet<i64> {
+    sensors
+        .iter()
+        .filter_map(|s| s.coverage_at_y(line))
+        .flatten()
+        .collect()
+}
+
+fn parse_input(inp: &str) -> Result<(Vec<Sensor>, Vec<Pos>), ParseError> {
+    let mut sensors = Vec::new();
+    let mut beacons = Vec::new();
+    for mut l in Lines::new(inp) {
+        l.tag("Sensor at x=")?;
+        let sx = l.number::<i64>()?;
+        l.tag(", y=")?;
+        let sy = l.number::<i64>()?;
+        l.tag(": closest beacon is at x=")?;
+        let bx = l.number::<i64>()?;
+        l.tag(", y=")?;
+        let by = l.number::<i64>()?;
+        l.end()?;
+
+        sensors.push(Sensor {
+            pos: (sx, sy),
+            range: ((sx - bx).abs() + (sy - by).abs()),
+        });
+        beacons.push((bx, by));
+    }
+    Ok((sensors, beacons))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_example() {
+        let mut day = Day15::default();
+        day.set_param("row", "10").unwrap();
+        day.set_param("limit", "20").unwrap();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(day.part_1(&inp), Answer::UInt(26));
+        assert_eq!(day.part_2(&inp), Some(Answer::Int(56000011)));
+
+        // Nothing is left uncovered this close to the sensors.
+        day.set_param("limit", "10").unwrap();
+        assert_eq!(day.part_2(&inp), None);
+    }
+
+    #[test]
+    fn test_coverage_at_y() {
+        let s = Sensor {
+            pos: (8, 7),
+            range: 9,
+        };
+
+        assert_eq!(s.coverage_at_y(7), Some(-1..=17));
+        assert_eq!(s.coverage_at_y(10), Some(2..=14));
+        assert_eq!(s.coverage_at_y(16), Some(8..=8));
+    }
+
+    #[test]
+    fn test_covers() {
+        let s = Sensor {
+            pos: (8, 7),
+            range: 9,
+        };
+
+        assert!(s.covers((8, 7)));
+        assert!(s.covers((8, 16)));
+        assert!(!s.covers((8, 17)));
+        assert!(s.covers((2, 10)));
+        assert!(!s.covers((1, 10)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let inp = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=9, y=16 closest beacon is at x=10, y=16\n";
+        assert_eq!(
+            parse_input(inp).unwrap_err().to_string(),
+            "line 2, column 20: expected `: closest beacon is at x=`, found ' '"
+        );
+
+        let inp = "Sensor at x=2, y=eighteen: closest beacon is at x=-2, y=15\n";
+        assert_eq!(
+            parse_input(inp).unwrap_err().to_string(),
+            "line 1, column 18: expected a number, found `eighteen:`"
+        );
+    }
+
+    #[test]
+
+    fn test_boundary() {
+        let s = Sensor {
+            pos: (0, 0),
+            range: 1,
+        };
+        assert_eq!(
+            s.boundary(),
+            vec![
+                (-2, 0),
+                (-1, 1),
+                (-1, -1),
+                (0, 2),
+                (0, -2),
+                (1, 1),
+                (1, -1),
+                (2, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        // Even without room for noise.
+        for limit in [20, 1, 0] {
+            let day = Day15 { row: 10, limit };
+            for seed in 0..20 {
+                let inp = day.parse(&day.generate(seed, 10).unwrap()).unwrap();
+                // Exactly one position is left for the distress beacon.
+                let uncovered: Vec<Pos> = (0..=limit)
+                    .flat_map(|x| (0..=limit).map(move |y| (x, y)))
+                    .filter(|pt| inp.0.iter().all(|s| !s.covers(*pt)))
+                    .collect();
+                assert_eq!(uncovered.len(), 1);
+                // Every sensor's own beacon is the only one in its range.
+                let beacons: HashSet<Pos> = inp.1.iter().copied().collect();
+                for s in &inp.0 {
+                    assert_eq!(beacons.iter().filter(|b| s.covers(**b)).count(), 1);
+                }
+                assert_eq!(
+                    day.part_2(&inp),
+                    Some(Answer::Int(uncovered[0].0 * 4000000 + uncovered[0].1))
+                );
+            }
+        }
+
+        let mut day = Day15::default();
+        assert_eq!(
+            day.set_param("limit", "-1").unwrap_err().to_string(),
+            "Invalid value -1 for parameter limit"
+        );
+    }
+
+    fn brute_force_no_beacon_count(sensors: &[Sensor], beacons: &[Pos], row: i64) -> usize {
+        let min_x = sensors.iter().map(|s| s.pos.0 - s.range).min().unwrap();
+        let max_x = sensors.iter().map(|s| s.pos.0 + s.range).max().unwrap();
+        (min_x..=max_x)
+            .filter(|x| !beacons.contains(&(*x, row)))
+            .filter(|x| sensors.iter().any(|s| s.covers((*x, row))))
+            .count()
+    }
+
+    fn brute_force_tuning_frequency(sensors: &[Sensor], limit: i64) -> Option<i64> {
+        let (x, y) = (0..=limit)
+            .flat_map(|x| (0..=limit).map(move |y| (x, y)))
+            .find(|pt| sensors.iter().all(|s| !s.covers(*pt)))?;
+        Some(x * 4000000 + y)
+    }
+
+    fn sensors() -> impl Strategy<Value = String> {
+        prop::collection::vec((-20..=20i64, -20..=20i64, -20..=20i64, -20..=20i64), 1..8).prop_map(
+            |sensors| {
+                sensors
+                    .into_iter()
+                    .map(|(sx, sy, bx, by)| {
+                        format!(
+                            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                            sx, sy, bx, by
+                        )
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    // Sensors that all stop just short of one point in the search area, which
+    // often leaves that as the only uncovered one.
+    fn around_one_point() -> impl Strategy<Value = (String, i64)> {
+        (0..=6i64)
+            .prop_flat_map(|limit| (Just(limit), 0..=limit, 0..=limit))
+            .prop_flat_map(|(limit, px, py)| {
+                let coord = -3..=limit + 3;
+                let sensors = prop::collection::vec((coord.clone(), coord), 1..10);
+                (Just(limit), Just((px, py)), sensors)
+            })
+            .prop_map(|(limit, (px, py), sensors)| {
+                let inp = sensors
+                    .into_iter()
+                    .filter(|s| *s != (px, py))
+                    .map(|(sx, sy)| {
+                        let range = (sx - px).abs() + (sy - py).abs() - 1;
+                        format!(
+                            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                            sx,
+                            sy,
+                            sx + range,
+                            sy
+                        )
+                    })
+                    .collect();
+                (inp, limit)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_no_beacon_count_matches_brute_force(inp in sensors(), row in -25..=25i64) {
+            let (sensors, beacons) = parse_input(&inp).unwrap();
+            prop_assert_eq!(
+                no_beacon_count(&sensors, &beacons, row),
+                brute_force_no_beacon_count(&sensors, &beacons, row)
+            );
+        }
+
+        #[test]
+        fn test_tuning_frequency_any_layout((inp, limit) in around_one_point()) {
+            let (sensors, _) = parse_input(&inp).unwrap();
+            let uncovered = (0..=limit)
+                .flat_map(|x| (0..=limit).map(move |y| (x, y)))
+                .filter(|pt| sensors.iter().all(|s| !s.covers(*pt)))
+                .count();
+            prop_assume!(uncovered == 1);
+            prop_assert_eq!(
+                tuning_frequency(&sensors, limit),
+                brute_force_tuning_frequency(&sensors, limit)
+            );
+        }
+
+        #[test]
+        fn test_tuning_frequency_matches_brute_force(
+            seed in any::<u64>(),
+            size in 4..12usize,
+            limit in 10..=40i64,
+        ) {
+            let day = Day15 { row: 0, limit };
+            let (sensors, _) = day.parse(&day.generate(seed, size).unwrap()).unwrap();
+            prop_assert_eq!(
+                tuning_frequency(&sensors, limit),
+                brute_force_tuning_frequency(&sensors, limit)
+            );
+        }
+    }
+}
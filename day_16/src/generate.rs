@@ -0,0 +1,56 @@
+use crate::MAX_VALVES;
+use rand::prelude::*;
+
+/// A connected tunnel network of `size` valves, clamped to 2..=64, where about
+/// a quarter of the valves have a flow rate.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let n = size.clamp(2, MAX_VALVES);
+
+    // Unique labels, starting with AA.
+    let mut labels: Vec<String> = ('A'..='Z')
+        .flat_map(|a| ('A'..='Z').map(move |b| format!("{}{}", a, b)))
+        .skip(1)
+        .collect();
+    labels.shuffle(&mut rng);
+    labels.truncate(n - 1);
+    labels.insert(0, "AA".to_string());
+
+    // A random spanning tree keeps everything reachable, the extra tunnels
+    // add some loops.
+    let mut tunnels = vec![Vec::new(); n];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for i in 1..n {
+        connect(i, rng.gen_range(0..i));
+    }
+    for _ in 0..n / 2 {
+        connect(rng.gen_range(0..n), rng.gen_range(0..n));
+    }
+
+    (0..n)
+        .map(|i| {
+            // Like the real inputs, AA is always stuck.
+            let flow_rate = match i > 0 && rng.gen_bool(0.25) {
+                true => rng.gen_range(1..=25),
+                false => 0,
+            };
+            let conns: Vec<&str> = tunnels[i].iter().map(|t| labels[*t].as_str()).collect();
+            let lead = match conns.len() {
+                1 => "tunnel leads to valve",
+                _ => "tunnels lead to valves",
+            };
+            format!(
+                "Valve {} has flow rate={}; {} {}\n",
+                labels[i],
+                flow_rate,
+                lead,
+                conns.join(", ")
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,353 @@
+use aoc_common::parse::Lines;
+use aoc_common::search::{self, AllPairs};
+use aoc_common::{parse_param, Answer, ParamError, ParseError, Solver};
+use std::cmp;
+use std::collections::HashMap;
+
+mod generate;
+
+// Opened valves are tracked as bits in a `u64`.
+const MAX_VALVES: usize = 64;
+
+type TunnelMap = Vec<Vec<usize>>;
+type Valves = Vec<Valve>;
+
+#[derive(Debug)]
+pub struct Valve {
+    idx: usize,
+    label: String,
+    flow_rate: u64,
+}
+
+#[derive(Eq, Hash, PartialEq)]
+struct State {
+    current_valve: usize,
+    mins_left: u64,
+    valve_state: u64,
+}
+
+pub struct Day16 {
+    /// Minutes until the volcano erupts.
+    pub minutes: u64,
+}
+
+impl Default for Day16 {
+    fn default() -> Self {
+        Day16 { minutes: 30 }
+    }
+}
+
+impl Solver for Day16 {
+    // The valves and the shortest distances between them.
+    type Input = (Valves, AllPairs<u64>);
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("minutes", self.minutes.to_string())]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "minutes" => self.minutes = parse_param(name, value)?,
+            _ => return Err(ParamError::unknown(name, self.params())),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        let (valves, tunnels) = parse_input(inp)?;
+
+        // Calculate the shortest distance between valves:
+        let edges = tunnels
+            .iter()
+            .enumerate()
+            .flat_map(|(i, conns)| conns.iter().map(move |c| (i, *c, 1)));
+        let dist = search::floyd_warshall(valves.len(), edges);
+        Ok((valves, dist))
+    }
+
+    fn part_1(&self, (valves, dist): &Self::Input) -> Answer {
+        // Now that we know all shortest distances, we have no reason to visit a
+        // valve without flow rate:
+        let start_valve = valves.iter().find(|v| v.label == "AA").unwrap();
+        let worthy_valves: Vec<&Valve> = valves.iter().filter(|v| v.flow_rate > 0).collect();
+
+        let mut cache: HashMap<State, u64> = HashMap::new();
+        check_options(
+            &mut cache,
+            dist,
+            &worthy_valves,
+            start_valve,
+            self.minutes,
+            0,
+        )
+        .into()
+    }
+
+    fn part_2(&self, _input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        Some(generate::input(seed, size))
+    }
+}
+
+fn check_options(
+    cache: &mut HashMap<State, u64>,
+    dist: &AllPairs<u64>,
+    valves: &Vec<&Valve>,
+    current_valve: &Valve,
+    mins_left: u64,
+    valve_state: u64,
+) -> u64 {
+    if mins_left <= 1 {
+        return 0;
+    }
+    let state = State {
+        current_valve: current_valve.idx,
+        mins_left,
+        valve_state,
+    };
+    if let Some(cache_hit) = cache.get(&state) {
+        return *cache_hit;
+    }
+
+    let mut new_mins_left = mins_left;
+    let mut new_valve_state = valve_state;
+    let mut flow = 0;
+
+    let possible_next: Vec<_> = valves
+        .iter()
+        .filter(|v| v.idx != current_valve.idx)
+        .collect();
+
+    // What's the best we can do if we don't open this valve now:
+    let best_if_skipped = possible_next
+        .iter()
+        .filter_map(|v| match dist.cost(current_valve.idx, v.idx) {
+            Some(d) if d + 1 < new_mins_left => Some(check_options(
+                cache,
+                dist,
+                valves,
+                v,
+                new_mins_left - d,
+                new_valve_state,
+            )),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+
+    // If the valve is not opened and worth opening, do so now:
+    if current_valve.flow_rate > 0 && (valve_state & (1 << current_valve.idx) == 0) {
+        new_valve_state = valve_state | (1 << current_valve.idx);
+        new_mins_left = mins_left - 1;
+        flow = new_mins_left * current_valve.flow_rate;
+    }
+
+    let best = possible_next
+        .iter()
+        .filter_map(|v| match dist.cost(current_valve.idx, v.idx) {
+            Some(d) if d + 1 < new_mins_left => Some(check_options(
+                cache,
+                dist,
+                valves,
+                v,
+                new_mins_left - d,
+                new_valve_state,
+            )),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+
+    let res = cmp::max(best_if_skipped, best + flow);
+    cache.insert(state, res);
+    res
+}
+
+fn parse_input(inp: &str) -> Result<(Valves, TunnelMap), ParseError> {
+    let mut label_to_idx: HashMap<String, usize> = HashMap::new();
+    let mut valves = Vec::new();
+    // The tunnels of each valve, by label and where it is in the input.
+    let mut conn_labels = Vec::new();
+
+    for (i, mut line) in Lines::new(inp).enumerate() {
+        if i == MAX_VALVES {
+            let expected = format!("end of input, after at most {} valves", MAX_VALVES);
+            return Err(line.error(expected));
+        }
+        line.tag("Valve ")?;
+        let name = line.word()?;
+        line.tag(" has flow rate=")?;
+        let flow_rate = line.number::<u64>()?;
+        line.any_tag(&["; tunnels lead to valves ", "; tunnel leads to valve "])?;
+        let conns = line.list(", ", |l| {
+            let at = (l.line(), l.column());
+            Ok((l.word()?, at))
+        })?;
+        line.end()?;
+
+        valves.push(Valve {
+            idx: i,
+            label: name.to_string(),
+            flow_rate,
+        });
+        label_to_idx.insert(name.to_string(), i);
+        conn_labels.push(conns);
+    }
+
+    if !label_to_idx.contains_key("AA") {
+        return Err(ParseError::at_end(inp, "a starting valve `AA`"));
+    }
+
+    let tunnels = conn_labels
+        .into_iter()
+        .map(|conns| {
+            conns
+                .into_iter()
+                .map(|(label, (line, column))| {
+                    label_to_idx.get(label).copied().ok_or_else(|| {
+                        ParseError::new(line, column, "a known valve", format!("`{}`", label))
+                    })
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((valves, tunnels))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_example() {
+        let day = Day16::default();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(day.part_1(&inp), Answer::UInt(1651));
+        // Part 2 (1707 for the example) isn't solved yet.
+        assert_eq!(day.part_2(&inp), None);
+    }
+
+    #[test]
+    fn test_too_many_valves() {
+        let day = Day16::default();
+        let inp = generate::input(1, MAX_VALVES);
+        assert!(day.parse(&inp).is_ok());
+        let inp = format!(
+            "{}Valve ZZ has flow rate=0; tunnel leads to valve AA\n",
+            inp
+        );
+        assert_eq!(
+            day.parse(&inp).unwrap_err().to_string(),
+            "line 65, column 1: expected end of input, after at most 64 valves, found `Valve`"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let inp = "Valve AA has flow rate=0; tunnels lead to valves BB, CC\nValve BB has flow rate=13; tunnel leads to valve AA\n";
+        assert_eq!(
+            parse_input(inp).unwrap_err().to_string(),
+            "line 1, column 54: expected a known valve, found `CC`"
+        );
+
+        let inp = "Valve AA has flow rate=0; tunnels lead to valve BB\n";
+        assert_eq!(
+            parse_input(inp).unwrap_err().to_string(),
+            "line 1, column 25: expected `; tunnels lead to valves ` or `; tunnel leads to valve `, found `;`"
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let day = Day16::default();
+        for seed in 0..5 {
+            let inp = day.parse(&day.generate(seed, 20).unwrap()).unwrap();
+            assert_eq!(inp.0.len(), 20);
+            // Every valve can be reached from AA.
+            assert!((0..20).all(|v| inp.1.cost(0, v).is_some()));
+            day.part_1(&inp);
+        }
+    }
+
+    // Spends every minute on the raw tunnels, either opening the current valve
+    // or walking to a neighbor.
+    fn brute_force(
+        cache: &mut HashMap<(usize, u64, u64), u64>,
+        valves: &Valves,
+        tunnels: &TunnelMap,
+        (at, mins_left, opened): (usize, u64, u64),
+    ) -> u64 {
+        if mins_left == 0 {
+            return 0;
+        }
+        if let Some(hit) = cache.get(&(at, mins_left, opened)) {
+            return *hit;
+        }
+
+        let mut best = 0;
+        if valves[at].flow_rate > 0 && opened & (1 << at) == 0 {
+            let released = (mins_left - 1) * valves[at].flow_rate;
+            let state = (at, mins_left - 1, opened | (1 << at));
+            best = released + brute_force(cache, valves, tunnels, state);
+        }
+        for next in &tunnels[at] {
+            let state = (*next, mins_left - 1, opened);
+            best = best.max(brute_force(cache, valves, tunnels, state));
+        }
+        cache.insert((at, mins_left, opened), best);
+        best
+    }
+
+    // A connected network of up to 7 valves, like the generator makes but
+    // with more of them worth opening.
+    fn network() -> impl Strategy<Value = String> {
+        (2..=7usize)
+            .prop_flat_map(|n| {
+                let parents: Vec<_> = (1..n).map(|i| 0..i).collect();
+                let extra = prop::collection::vec((0..n, 0..n), 0..3);
+                let flows = prop::collection::vec(prop_oneof![Just(0), 1..=20u64], n);
+                (parents, extra, flows)
+            })
+            .prop_map(|(parents, extra, flows)| {
+                let n = flows.len();
+                let mut tunnels = vec![Vec::new(); n];
+                let edges = parents.into_iter().enumerate().map(|(i, p)| (i + 1, p));
+                for (a, b) in edges.chain(extra) {
+                    if a != b && !tunnels[a].contains(&b) {
+                        tunnels[a].push(b);
+                        tunnels[b].push(a);
+                    }
+                }
+                let label = |i: usize| (b'A' + i as u8) as char;
+                (0..n)
+                    .map(|i| {
+                        let conns: Vec<String> = tunnels[i]
+                            .iter()
+                            .map(|t| format!("{0}{0}", label(*t)))
+                            .collect();
+                        format!(
+                            "Valve {0}{0} has flow rate={1}; tunnels lead to valves {2}\n",
+                            label(i),
+                            flows[i],
+                            conns.join(", ")
+                        )
+                    })
+                    .collect()
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_check_options_matches_brute_force(inp in network(), minutes in 0..=12u64) {
+            let day = Day16 { minutes };
+            let (valves, tunnels) = parse_input(&inp).unwrap();
+            let expected = brute_force(&mut HashMap::new(), &valves, &tunnels, (0, minutes, 0));
+            prop_assert_eq!(day.part_1(&day.parse(&inp).unwrap()), Answer::UInt(expected));
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use rand::prelude::*;
+
+/// A jet pattern of `size` pushes.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut res: String = (0..size.max(1))
+        .map(|_| match rng.gen_bool(0.5) {
+            true => '<',
+            false => '>',
+        })
+        .collect();
+    res.push('\n');
+    res
+}
@@ -0,0 +1,347 @@
+use aoc_common::parse::Lines;
+use aoc_common::{parse_param, Answer, ParamError, ParseError, Solver};
+use std::collections::{hash_map::Entry, HashMap};
+
+mod generate;
+
+pub struct Day17 {
+    /// Rocks to drop in part 1.
+    pub p1_rocks: usize,
+    /// Rocks to drop in part 2.
+    pub p2_rocks: usize,
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Day17 {
+            p1_rocks: 2022,
+            p2_rocks: 1_000_000_000_000,
+        }
+    }
+}
+
+impl Solver for Day17 {
+    type Input = Vec<Dir>;
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("p1_rocks", self.p1_rocks.to_string()),
+            ("p2_rocks", self.p2_rocks.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "p1_rocks" => self.p1_rocks = parse_param(name, value)?,
+            "p2_rocks" => self.p2_rocks = parse_param(name, value)?,
+            _ => return Err(ParamError::unknown(name, self.params())),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, jets: &Self::Input) -> Answer {
+        let mut tower = Vec::new();
+
+        let mut wind_idx = 0;
+        for shape in SHAPES.into_iter().cycle().take(self.p1_rocks) {
+            wind_idx = simulate_rock(&mut tower, jets, wind_idx, shape);
+        }
+        tower.len().into()
+    }
+
+    fn part_2(&self, jets: &Self::Input) -> Option<Answer> {
+        let mut tower = Vec::new();
+        let mut wind_idx = 0;
+
+        let mut seen_states = HashMap::with_capacity(1_024);
+        let mut cycle_height = 0;
+        let mut n = 0;
+        while n < self.p2_rocks {
+            let shape_idx = n % SHAPES.len();
+            let shape = SHAPES[shape_idx];
+
+            wind_idx = simulate_rock(&mut tower, jets, wind_idx, shape);
+            n += 1;
+
+            // Check if we've seen this pattern before:
+            let state = (surface(&tower), shape_idx, wind_idx);
+
+            match seen_states.entry(state) {
+                Entry::Occupied(e) => {
+                    let (old_n, old_height) = e.get();
+                    let num_rocks_in_cycle = n - old_n;
+                    let num_cycles = (self.p2_rocks - n) / num_rocks_in_cycle;
+                    n += num_rocks_in_cycle * num_cycles;
+                    cycle_height += num_cycles * (tower.len() - old_height);
+                    seen_states.clear();
+                }
+                Entry::Vacant(e) => {
+                    e.insert((n, tower.len()));
+                }
+            }
+        }
+
+        Some((tower.len() + cycle_height).into())
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        Some(generate::input(seed, size))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Dir {
+    Left,
+    Right,
+}
+
+// This genius move comes from Reddit user Gix:
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Shape(u32);
+
+const SHAPES: [Shape; 5] = [
+    // Shapes start with LEFT edge 2 spaces from the wall (so 7 - 2 => 5)
+    Shape(0b00000000_00000000_00000000_00011110),
+    Shape(0b00000000_00001000_00011100_00001000),
+    Shape(0b00000000_00000100_00000100_00011100),
+    Shape(0b00010000_00010000_00010000_00010000),
+    Shape(0b00000000_00000000_00011000_00011000),
+];
+const LEFT_EDGE: Shape = Shape(0b01000000_01000000_01000000_01000000);
+const RIGHT_EDGE: Shape = Shape(0b0000001_00000001_00000001_00000001);
+
+impl Shape {
+    const fn intersects(&self, mask: u32) -> bool {
+        self.0 & mask != 0
+    }
+
+    fn blow(&mut self, direction: Dir, tower_mask: u32) {
+        let new_pos = match direction {
+            Dir::Left => {
+                if self.0 & LEFT_EDGE.0 == 0 {
+                    self.0 << 1
+                } else {
+                    return;
+                }
+            }
+            Dir::Right => {
+                if self.0 & RIGHT_EDGE.0 == 0 {
+                    self.0 >> 1
+                } else {
+                    return;
+                }
+            }
+        };
+
+        if new_pos & tower_mask == 0 {
+            self.0 = new_pos;
+        }
+    }
+
+    fn as_lines(self) -> impl Iterator<Item = u8> {
+        self.0.to_le_bytes().into_iter().take_while(|b| *b != 0)
+    }
+}
+
+fn tower_slice_mask(tower: &[u8], height: usize) -> u32 {
+    // Returns the tower mask at the given height.
+    if height >= tower.len() {
+        0
+    } else {
+        tower[height..]
+            .iter()
+            .take(4)
+            .rev()
+            .fold(0u32, |acc, b| (acc << 8) | *b as u32)
+    }
+}
+
+/// How far down `surface` looks. A column that never fills up would otherwise
+/// keep the surface growing, and the cycle would never show.
+const SURFACE_DEPTH: usize = 64;
+
+/// The free cells that falling rocks can still reach, row by row from the top
+/// down to at most `SURFACE_DEPTH` rows. This is a heuristic: two towers with
+/// the same surface only behave the same as long as no rock falls deeper
+/// than that. Rocks settle within a few rows of the top on any realistic
+/// jet pattern, but one could slip down an open column past the cut.
+fn surface(tower: &[u8]) -> Vec<u8> {
+    const FREE: u8 = 0b01111111;
+    let mut res = Vec::new();
+    let mut reachable = FREE;
+    for row in tower.iter().rev().take(SURFACE_DEPTH) {
+        let free = !row & FREE;
+        // Fall in from the row above, then slide sideways as far as possible.
+        let mut cur = reachable & free;
+        loop {
+            let spread = (cur | cur << 1 | cur >> 1) & free;
+            if spread == cur {
+                break;
+            }
+            cur = spread;
+        }
+        if cur == 0 {
+            break;
+        }
+        res.push(cur);
+        reachable = cur;
+    }
+    res
+}
+
+fn simulate_rock(
+    tower: &mut Vec<u8>,
+    wind: &[Dir],
+    mut wind_idx: usize,
+    mut shape: Shape,
+) -> usize {
+    let mut height = tower.len() + 3;
+
+    loop {
+        let wind_dir = wind[wind_idx];
+        wind_idx += 1;
+        if wind_idx == wind.len() {
+            wind_idx = 0;
+        }
+
+        let current_mask = tower_slice_mask(tower, height);
+
+        shape.blow(wind_dir, current_mask);
+
+        if height > tower.len() {
+            height -= 1;
+        } else if height == 0 || shape.intersects(tower_slice_mask(tower, height - 1)) {
+            for byte in shape.as_lines() {
+                if height < tower.len() {
+                    tower[height] |= byte;
+                } else {
+                    tower.push(byte);
+                }
+                height += 1;
+            }
+            return wind_idx;
+        } else {
+            height -= 1;
+        }
+    }
+}
+
+fn parse_input(inp: &str) -> Result<Vec<Dir>, ParseError> {
+    let mut lines = Lines::new(inp);
+    let mut line = lines.expect("a jet pattern")?;
+    let mut jets = Vec::new();
+    loop {
+        jets.push(match line.one_of("<>")? {
+            '<' => Dir::Left,
+            _ => Dir::Right,
+        });
+        if line.is_empty() {
+            break;
+        }
+    }
+    lines.end()?;
+    Ok(jets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_example() {
+        let day = Day17::default();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(day.part_1(&inp), Answer::UInt(3068));
+        assert_eq!(day.part_2(&inp), Some(Answer::UInt(1514285714288)));
+    }
+
+    #[test]
+    fn test_open_column() {
+        // The rightmost column never fills, so the surface reaches the floor.
+        let day = Day17::default();
+        let inp = day.parse("<\n").unwrap();
+        assert_eq!(day.part_2(&inp), Some(Answer::UInt(2200000000000)));
+    }
+
+    #[test]
+    fn test_generate() {
+        let day = Day17::default();
+        let generated = day.generate(7, 100).unwrap();
+        assert_eq!(generated, day.generate(7, 100).unwrap());
+        let inp = day.parse(&generated).unwrap();
+        assert_eq!(inp.len(), 100);
+        day.part_2(&inp).unwrap();
+    }
+
+    // Drops rocks one cell at a time into a set of occupied cells, with y
+    // growing upwards.
+    fn brute_force(jets: &[Dir], rocks: usize) -> i64 {
+        let shapes: [&[(i64, i64)]; 5] = [
+            &[(0, 0), (1, 0), (2, 0), (3, 0)],
+            &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            &[(0, 0), (0, 1), (0, 2), (0, 3)],
+            &[(0, 0), (1, 0), (0, 1), (1, 1)],
+        ];
+        let mut occupied: HashSet<(i64, i64)> = HashSet::new();
+        let mut height = 0;
+        let mut jet = 0;
+        for shape in shapes.into_iter().cycle().take(rocks) {
+            let fits = |(x, y): (i64, i64)| {
+                shape.iter().all(|(dx, dy)| {
+                    let (x, y) = (x + dx, y + dy);
+                    (0..7).contains(&x) && y >= 0 && !occupied.contains(&(x, y))
+                })
+            };
+            let mut pos = (2, height + 3);
+            loop {
+                let dx = match jets[jet % jets.len()] {
+                    Dir::Left => -1,
+                    Dir::Right => 1,
+                };
+                jet += 1;
+                if fits((pos.0 + dx, pos.1)) {
+                    pos.0 += dx;
+                }
+                match fits((pos.0, pos.1 - 1)) {
+                    true => pos.1 -= 1,
+                    false => break,
+                }
+            }
+            for (dx, dy) in shape {
+                occupied.insert((pos.0 + dx, pos.1 + dy));
+                height = height.max(pos.1 + dy + 1);
+            }
+        }
+        height
+    }
+
+    fn jets() -> impl Strategy<Value = Vec<Dir>> {
+        prop::collection::vec(prop_oneof![Just(Dir::Left), Just(Dir::Right)], 1..=40)
+    }
+
+    proptest! {
+        #[test]
+        fn test_simulation_matches_brute_force(jets in jets(), rocks in 0..300usize) {
+            let day = Day17 { p1_rocks: rocks, p2_rocks: rocks };
+            prop_assert_eq!(day.part_1(&jets), Answer::UInt(brute_force(&jets, rocks) as u64));
+        }
+    }
+
+    proptest! {
+        // Fewer cases, as each drops enough rocks for the cycles to repeat.
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn test_cycle_skip_matches_simulation(jets in jets(), rocks in 0..3000usize) {
+            let day = Day17 { p1_rocks: rocks, p2_rocks: rocks };
+            prop_assert_eq!(day.part_2(&jets), Some(day.part_1(&jets)));
+        }
+    }
+}
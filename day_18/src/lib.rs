@@ -0,0 +1,100 @@
+use aoc_common::parse::Lines;
+use aoc_common::{search, Answer, ParseError, Solver};
+use std::collections::HashSet;
+
+type Cube = (i8, i8, i8);
+
+#[derive(Default)]
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Input = HashSet<Cube>;
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, cubes: &Self::Input) -> Answer {
+        let p1: usize = cubes
+            .iter()
+            .map(|d| boundary(*d).iter().filter(|b| !cubes.contains(b)).count())
+            .sum();
+        p1.into()
+    }
+
+    fn part_2(&self, cubes: &Self::Input) -> Option<Answer> {
+        let (lower_bound, upper_bound) = bounding_box(cubes);
+        Some(flood(cubes, lower_bound, upper_bound).into())
+    }
+}
+
+fn parse_input(inp: &str) -> Result<HashSet<Cube>, ParseError> {
+    let cubes: HashSet<Cube> = Lines::new(inp)
+        .map(|mut l| {
+            let x = l.number()?;
+            l.tag(",")?;
+            let y = l.number()?;
+            l.tag(",")?;
+            let z = l.number()?;
+            l.end()?;
+            Ok((x, y, z))
+        })
+        .collect::<Result<_, _>>()?;
+    if cubes.is_empty() {
+        return Err(ParseError::at_end(inp, "a cube"));
+    }
+    Ok(cubes)
+}
+
+fn bounding_box(cubes: &HashSet<Cube>) -> (Cube, Cube) {
+    let min_x = cubes.iter().map(|c| c.0).min().unwrap() - 2;
+    let max_x = cubes.iter().map(|c| c.0).max().unwrap() + 2;
+    let min_y = cubes.iter().map(|c| c.1).min().unwrap() - 2;
+    let max_y = cubes.iter().map(|c| c.1).max().unwrap() + 2;
+    let min_z = cubes.iter().map(|c| c.2).min().unwrap() - 2;
+    let max_z = cubes.iter().map(|c| c.2).max().unwrap() + 2;
+
+    ((min_x, min_y, min_z), (max_x, max_y, max_z))
+}
+
+fn boundary(c: Cube) -> [Cube; 6] {
+    [
+        (c.0 + 1, c.1, c.2),
+        (c.0 - 1, c.1, c.2),
+        (c.0, c.1 + 1, c.2),
+        (c.0, c.1 - 1, c.2),
+        (c.0, c.1, c.2 + 1),
+        (c.0, c.1, c.2 - 1),
+    ]
+}
+
+/// Counts the faces of the drop that touch the air outside of it, by flooding
+/// the bounding box from its corner.
+fn flood(drop: &HashSet<Cube>, start: Cube, end: Cube) -> usize {
+    let in_box = |c: &Cube| {
+        (start.0..=end.0).contains(&c.0)
+            && (start.1..=end.1).contains(&c.1)
+            && (start.2..=end.2).contains(&c.2)
+    };
+    let outside = search::reachable([start], |curr| {
+        boundary(*curr)
+            .into_iter()
+            .filter(|n| in_box(n) && !drop.contains(n))
+    });
+    outside
+        .iter()
+        .map(|c| boundary(*c).iter().filter(|n| drop.contains(n)).count())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day18.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day18.part_1(&inp), Answer::UInt(64));
+        assert_eq!(Day18.part_2(&inp), Some(Answer::UInt(58)));
+    }
+}
@@ -0,0 +1,274 @@
+use aoc_common::{debug, parse_param, Answer, ParamError, ParseError, Solver};
+use std::cmp::max;
+use std::collections::HashMap;
+
+mod parse;
+
+pub struct Day19 {
+    /// Minutes to crack geodes in part 1.
+    pub p1_minutes: usize,
+    /// Minutes to crack geodes in part 2.
+    pub p2_minutes: usize,
+}
+
+impl Default for Day19 {
+    fn default() -> Self {
+        Day19 {
+            p1_minutes: 24,
+            p2_minutes: 32,
+        }
+    }
+}
+
+impl Solver for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("p1_minutes", self.p1_minutes.to_string()),
+            ("p2_minutes", self.p2_minutes.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "p1_minutes" => self.p1_minutes = parse_param(name, value)?,
+            "p2_minutes" => self.p2_minutes = parse_param(name, value)?,
+            _ => return Err(ParamError::unknown(name, self.params())),
+        }
+        Ok(())
+    }
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        let blueprints = parse::blueprints(inp)?;
+        debug!("{:?}", blueprints);
+        Ok(blueprints)
+    }
+
+    fn part_1(&self, blueprints: &Self::Input) -> Answer {
+        let initial_state = State {
+            mins_left: self.p1_minutes,
+            resources: [0; 4],
+            bots: [1, 0, 0, 0],
+        };
+
+        let quality_sum: u64 = blueprints
+            .iter()
+            .map(|b| b.id * max_geodes(&mut HashMap::new(), b, &initial_state, &mut 0))
+            .sum();
+        quality_sum.into()
+    }
+
+    fn part_2(&self, blueprints: &Self::Input) -> Option<Answer> {
+        let initial_state = State {
+            mins_left: self.p2_minutes,
+            resources: [0; 4],
+            bots: [1, 0, 0, 0],
+        };
+        let geode_product: u64 = blueprints
+            .iter()
+            .take(3)
+            .map(|b| {
+                let best = max_geodes(&mut HashMap::new(), b, &initial_state, &mut 0);
+                debug!("Blueprint {}: Max geodes: {}", b.id, best);
+                best
+            })
+            .product();
+        Some(geode_product.into())
+    }
+}
+
+// Resource indices:
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+// Cost of (ore, clay, obsidian, geode)
+type Cost = [u64; 4];
+
+#[derive(Debug)]
+pub struct Blueprint {
+    id: u64,
+    costs: [Cost; 4],
+    // OPTIMIZATION: We don't need to build bots of a type, if we're already
+    // producing enough of this resource to build the most expensive bot
+    // each minute.
+    max_prod_needed: [u64; 4],
+}
+
+impl Blueprint {
+    fn new(id: u64, costs: [Cost; 4]) -> Self {
+        let mut max_prod_needed = [0, 0, 0, u64::MAX];
+        for cost in costs {
+            for res in [ORE, CLAY, OBSIDIAN] {
+                max_prod_needed[res] = max(max_prod_needed[res], cost[res]);
+            }
+        }
+        Blueprint {
+            id,
+            costs,
+            max_prod_needed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct State {
+    mins_left: usize,
+    resources: [u64; 4],
+    bots: [u64; 4],
+}
+
+fn max_geodes(
+    cache: &mut HashMap<State, u64>,
+    blueprint: &Blueprint,
+    state: &State,
+    current_max: &mut u64,
+) -> u64 {
+    if state.mins_left == 0 {
+        return state.resources[GEODE];
+    }
+
+    if let Some(hit) = cache.get(state) {
+        return *hit;
+    }
+
+    // OPTIMIZATION: guess the maximum we can still achieve on this path,
+    // and stop if less than what we already achieved.
+    let guaranteed_res: u64 = state.resources[GEODE] + (state.mins_left as u64 * state.bots[GEODE]);
+    let upper_bound: u64 = guaranteed_res + (1..=state.mins_left).sum::<usize>() as u64;
+    if upper_bound < *current_max {
+        return *current_max;
+    }
+
+    let mut next_state = state.clone();
+    next_state.mins_left -= 1;
+    // Harvest resources:
+    for res in ORE..=GEODE {
+        next_state.resources[res] += state.bots[res];
+    }
+
+    // Try our different options:
+    let mut outcomes: Vec<u64> = Vec::new();
+    // 1: Do nothing:
+    outcomes.push(max_geodes(cache, blueprint, &next_state, current_max));
+
+    // 2: Attempt to build some bots:
+    // OPTIMIZATION: Don't build a bot if it can't harvest anyway:
+    if next_state.mins_left > 0 {
+        for bot in ORE..=GEODE {
+            // OPTIMIZATION: Don't overproduce unneccessary bots:
+            if state.bots[bot] >= blueprint.max_prod_needed[bot] {
+                continue;
+            }
+
+            let cost = blueprint.costs[bot];
+            if cost.iter().zip(state.resources).all(|(c, r)| *c <= r) {
+                let mut _state = next_state.clone();
+                _state.bots[bot] += 1;
+                for (res, c) in cost.iter().enumerate() {
+                    _state.resources[res] -= c;
+                }
+                outcomes.push(max_geodes(cache, blueprint, &_state, current_max));
+            }
+        }
+    }
+
+    let res = *outcomes.iter().max().unwrap();
+    cache.insert(state.clone(), res);
+    *current_max = max(*current_max, res);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_example() {
+        let day = Day19::default();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(day.part_1(&inp), Answer::UInt(33));
+    }
+
+    #[test]
+    fn test_example_part_2() {
+        // The same 9 and 12 geodes as in part 1, multiplied instead.
+        let mut day = Day19::default();
+        day.set_param("p2_minutes", "24").unwrap();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(day.part_2(&inp), Some(Answer::UInt(108)));
+    }
+
+    #[test]
+    #[ignore = "takes about a minute, even in release builds"]
+    fn test_example_part_2_full() {
+        let day = Day19::default();
+        let inp = day.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(day.part_2(&inp), Some(Answer::UInt(3472)));
+    }
+
+    // Tries every choice in every minute, without any of the pruning.
+    fn brute_force(cache: &mut HashMap<State, u64>, blueprint: &Blueprint, state: &State) -> u64 {
+        if state.mins_left == 0 {
+            return state.resources[GEODE];
+        }
+        if let Some(hit) = cache.get(state) {
+            return *hit;
+        }
+
+        let mut next_state = state.clone();
+        next_state.mins_left -= 1;
+        for res in ORE..=GEODE {
+            next_state.resources[res] += state.bots[res];
+        }
+
+        let mut best = brute_force(cache, blueprint, &next_state);
+        for bot in ORE..=GEODE {
+            let cost = blueprint.costs[bot];
+            if cost.iter().zip(state.resources).all(|(c, r)| *c <= r) {
+                let mut built = next_state.clone();
+                built.bots[bot] += 1;
+                for (res, c) in cost.iter().enumerate() {
+                    built.resources[res] -= c;
+                }
+                best = max(best, brute_force(cache, blueprint, &built));
+            }
+        }
+        cache.insert(state.clone(), best);
+        best
+    }
+
+    fn blueprint() -> impl Strategy<Value = String> {
+        (1..=4u64, 1..=4u64, 1..=4u64, 1..=8u64, 1..=4u64, 1..=6u64).prop_map(
+            |(ore, clay, obs_ore, obs_clay, geo_ore, geo_obs)| {
+                format!(
+                    "Blueprint 1: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                     Each obsidian robot costs {} ore and {} clay. \
+                     Each geode robot costs {} ore and {} obsidian.\n",
+                    ore, clay, obs_ore, obs_clay, geo_ore, geo_obs
+                )
+            },
+        )
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn test_max_geodes_matches_brute_force(inp in blueprint(), mins_left in 0..=16usize) {
+            let blueprint = &parse::blueprints(&inp).unwrap()[0];
+            let state = State {
+                mins_left,
+                resources: [0; 4],
+                bots: [1, 0, 0, 0],
+            };
+            prop_assert_eq!(
+                max_geodes(&mut HashMap::new(), blueprint, &state, &mut 0),
+                brute_force(&mut HashMap::new(), blueprint, &state)
+            );
+        }
+    }
+}
@@ -0,0 +1,258 @@
+//! Blueprints are read in two steps: the input is split into tokens first, and
+//! those are then matched against this grammar:
+//!
+//! ```text
+//! blueprint := "Blueprint" NUMBER ":" robot+
+//! robot     := "Each" RESOURCE "robot" "costs" cost ("and" cost)* "."
+//! cost      := NUMBER RESOURCE
+//! RESOURCE  := "ore" | "clay" | "obsidian" | "geode"
+//! ```
+//!
+//! Whitespace, line breaks included, only separates tokens, so a blueprint can
+//! be on one line or spread over several like in the puzzle text.
+use crate::{Blueprint, Cost};
+use aoc_common::ParseError;
+
+const RESOURCES: [&str; 4] = ["ore", "clay", "obsidian", "geode"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Word,
+    Number,
+    Colon,
+    Period,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: Kind,
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl Token<'_> {
+    fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, self.column, expected, format!("`{}`", self.text))
+    }
+}
+
+fn tokenize(inp: &str) -> Result<Vec<Token<'_>>, ParseError> {
+    let mut tokens = Vec::new();
+    for (i, line) in inp.lines().enumerate() {
+        let mut chars = line.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            let kind = match c {
+                _ if c.is_whitespace() => continue,
+                ':' => Kind::Colon,
+                '.' => Kind::Period,
+                _ if c.is_ascii_alphabetic() => Kind::Word,
+                _ if c.is_ascii_digit() => Kind::Number,
+                _ => {
+                    let column = line[..start].chars().count() + 1;
+                    let found = format!("`{}`", c);
+                    return Err(ParseError::new(i + 1, column, "a word or a number", found));
+                }
+            };
+            let mut end = start + c.len_utf8();
+            if matches!(kind, Kind::Word | Kind::Number) {
+                while let Some((j, _)) = chars.next_if(|(_, c)| match kind {
+                    Kind::Word => c.is_ascii_alphabetic(),
+                    _ => c.is_ascii_digit(),
+                }) {
+                    end = j + 1;
+                }
+            }
+            tokens.push(Token {
+                kind,
+                text: &line[start..end],
+                line: i + 1,
+                column: line[..start].chars().count() + 1,
+            });
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+    // Where to report anything missing from the end.
+    end_line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token<'a>> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self, expected: &str) -> Result<Token<'a>, ParseError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .copied()
+            .ok_or_else(|| ParseError::new(self.end_line, 1, expected, "end of input"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn next_is(&self, word: &str) -> bool {
+        self.peek().is_some_and(|t| t.text == word)
+    }
+
+    fn keyword(&mut self, word: &str) -> Result<(), ParseError> {
+        let expected = format!("`{}`", word);
+        let token = self.next(&expected)?;
+        match token.kind == Kind::Word && token.text == word {
+            true => Ok(()),
+            false => Err(token.error(expected)),
+        }
+    }
+
+    fn punct(&mut self, kind: Kind, expected: &str) -> Result<(), ParseError> {
+        let token = self.next(expected)?;
+        match token.kind == kind {
+            true => Ok(()),
+            false => Err(token.error(expected)),
+        }
+    }
+
+    fn number(&mut self) -> Result<u64, ParseError> {
+        let token = self.next("a number")?;
+        match token.kind {
+            Kind::Number => token.text.parse().map_err(|_| token.error("a number")),
+            _ => Err(token.error("a number")),
+        }
+    }
+
+    fn resource(&mut self) -> Result<(usize, Token<'a>), ParseError> {
+        let expected = "a resource (`ore`, `clay`, `obsidian` or `geode`)";
+        let token = self.next(expected)?;
+        match RESOURCES.iter().position(|r| *r == token.text) {
+            Some(idx) => Ok((idx, token)),
+            None => Err(token.error(expected)),
+        }
+    }
+
+    fn blueprint(&mut self) -> Result<Blueprint, ParseError> {
+        self.keyword("Blueprint")?;
+        let id = self.number()?;
+        self.punct(Kind::Colon, "`:`")?;
+
+        let mut costs: [Option<Cost>; 4] = [None; 4];
+        loop {
+            self.keyword("Each")?;
+            let (robot, token) = self.resource()?;
+            if costs[robot].is_some() {
+                return Err(token.error("a robot that wasn't priced yet"));
+            }
+            self.keyword("robot")?;
+            self.keyword("costs")?;
+
+            let mut cost = [0; 4];
+            let mut seen = [false; 4];
+            loop {
+                let amount = self.number()?;
+                let (res, token) = self.resource()?;
+                if seen[res] {
+                    return Err(token.error("a resource not in this cost yet"));
+                }
+                cost[res] = amount;
+                seen[res] = true;
+                if !self.next_is("and") {
+                    break;
+                }
+                self.keyword("and")?;
+            }
+            self.punct(Kind::Period, "`and` or `.`")?;
+            costs[robot] = Some(cost);
+
+            if !self.next_is("Each") {
+                break;
+            }
+        }
+
+        let mut found = [[0; 4]; 4];
+        for (robot, cost) in costs.iter().enumerate() {
+            found[robot] = cost.ok_or_else(|| {
+                let expected = format!("`Each` for the price of the {} robot", RESOURCES[robot]);
+                match self.peek() {
+                    Some(token) => token.error(expected),
+                    None => ParseError::new(self.end_line, 1, expected, "end of input"),
+                }
+            })?;
+        }
+        Ok(Blueprint::new(id, found))
+    }
+}
+
+pub fn blueprints(inp: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(inp)?,
+        pos: 0,
+        end_line: inp.lines().count() + 1,
+    };
+    let mut blueprints = Vec::new();
+    while parser.peek().is_some() {
+        blueprints.push(parser.blueprint()?);
+    }
+    Ok(blueprints)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GEODE;
+
+    #[test]
+    fn test_layouts() {
+        let one_line = blueprints(include_str!("../example.txt")).unwrap();
+        let spread = blueprints(
+            "Blueprint 1:\n  Each ore robot costs 4 ore.\n  Each clay robot costs 2 ore.\n  Each obsidian robot costs 3 ore and 14 clay.\n  Each geode robot costs 2 ore and 7 obsidian.\n\nBlueprint 2:\n  Each ore robot costs 2 ore.\n  Each clay robot costs 3 ore.\n  Each obsidian robot costs 3 ore and 8 clay.\n  Each geode robot costs 3 ore and 12 obsidian.\n",
+        )
+        .unwrap();
+        assert_eq!(format!("{:?}", one_line), format!("{:?}", spread));
+        assert_eq!(one_line[0].costs[2], [3, 14, 0, 0]);
+    }
+
+    #[test]
+    fn test_any_costs() {
+        let inp = "Blueprint 7: Each geode robot costs 1 clay. Each ore robot costs 1 ore and 2 clay and 3 obsidian and 4 geode. Each obsidian robot costs 5 ore. Each clay robot costs 6 ore.";
+        let blueprint = &blueprints(inp).unwrap()[0];
+        assert_eq!(blueprint.costs[0], [1, 2, 3, 4]);
+        assert_eq!(blueprint.costs[GEODE], [0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |inp: &str| blueprints(inp).unwrap_err().to_string();
+        let robots = "Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay.";
+        assert_eq!(
+            error(&format!("Blueprint 1: {} Each geode robot costs 2 diamond.", robots)),
+            "line 1, column 141: expected a resource (`ore`, `clay`, `obsidian` or `geode`), found `diamond`"
+        );
+        assert_eq!(
+            error(&format!("Blueprint 1: {}\nBlueprint 2:", robots)),
+            "line 2, column 1: expected `Each` for the price of the geode robot, found `Blueprint`"
+        );
+        assert_eq!(
+            error(&format!(
+                "Blueprint 1: {} Each clay robot costs 1 ore.",
+                robots
+            )),
+            "line 1, column 121: expected a robot that wasn't priced yet, found `clay`"
+        );
+        assert_eq!(
+            error("Blueprint 1: Each ore robot costs 4 ore and 1 ore."),
+            "line 1, column 47: expected a resource not in this cost yet, found `ore`"
+        );
+        assert_eq!(
+            error("Blueprint 1: Each ore robot costs 4 ore"),
+            "line 2, column 1: expected `and` or `.`, found end of input"
+        );
+        assert_eq!(
+            error("Blueprint 1; Each"),
+            "line 1, column 12: expected a word or a number, found `;`"
+        );
+    }
+}
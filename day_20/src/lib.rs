@@ -0,0 +1,81 @@
+use aoc_common::parse::Lines;
+use aoc_common::{Answer, ParseError, Solver};
+use std::collections::VecDeque;
+
+#[derive(Default)]
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input = VecDeque<i64>;
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, encrypted: &Self::Input) -> Answer {
+        let decrypted = mix_decrypt(encrypted, 1);
+        sum_coords(&decrypted).into()
+    }
+
+    fn part_2(&self, encrypted: &Self::Input) -> Option<Answer> {
+        const DECRYPTION_KEY: i64 = 811589153;
+        let encrypted: VecDeque<i64> = encrypted.iter().map(|x| x * DECRYPTION_KEY).collect();
+        let decrypted = mix_decrypt(&encrypted, 10);
+        Some(sum_coords(&decrypted).into())
+    }
+}
+
+fn parse_input(inp: &str) -> Result<VecDeque<i64>, ParseError> {
+    Lines::new(inp)
+        .map(|mut l| {
+            let x = l.number()?;
+            l.end()?;
+            Ok(x)
+        })
+        .collect()
+}
+
+fn mix_decrypt(instr: &VecDeque<i64>, rounds: usize) -> VecDeque<i64> {
+    let mut mix: VecDeque<(usize, i64)> = instr.clone().iter().copied().enumerate().collect();
+    let size = mix.len();
+
+    for _ in 0..rounds {
+        for (idx, instr) in instr.iter().enumerate() {
+            if *instr == 0 {
+                continue;
+            }
+            let actual_idx = mix.iter().position(|(i, _)| *i == idx).unwrap();
+
+            mix.rotate_left(actual_idx);
+            let (old_idx, elem) = mix.pop_front().unwrap();
+            let positions = elem.unsigned_abs() as usize % (size - 1);
+            match elem > 0 {
+                true => mix.rotate_left(positions),
+                false => mix.rotate_right(positions),
+            };
+            mix.push_front((old_idx, elem));
+        }
+    }
+
+    mix.iter().map(|(_, elem)| *elem).collect()
+}
+
+fn sum_coords(decrypted: &VecDeque<i64>) -> i64 {
+    let pos_of_zero = decrypted.iter().position(|x| *x == 0).unwrap();
+    [1000, 2000, 3000]
+        .iter()
+        .map(|i| decrypted[(pos_of_zero + i) % decrypted.len()])
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day20.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day20.part_1(&inp), Answer::Int(3));
+        assert_eq!(Day20.part_2(&inp), Some(Answer::Int(1623178306)));
+    }
+}
@@ -0,0 +1,135 @@
+use rand::prelude::*;
+use std::collections::HashSet;
+
+// Keeps every intermediate value far away from overflowing.
+const MAX_VALUE: i64 = 1_000_000_000_000;
+
+struct Generator {
+    rng: StdRng,
+    names: HashSet<String>,
+    lines: Vec<String>,
+}
+
+impl Generator {
+    fn name(&mut self) -> String {
+        loop {
+            let name: String = (0..4)
+                .map(|_| self.rng.gen_range(b'a'..=b'z') as char)
+                .collect();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    /// Adds about `budget` monkeys that together yell `value`, and returns the
+    /// name of the one at the top.
+    fn constant(&mut self, value: i64, budget: usize) -> String {
+        let name = self.name();
+        if budget <= 1 {
+            self.lines.push(format!("{}: {}", name, value));
+            return name;
+        }
+
+        let divisor = (2..=10).find(|d| value != 0 && value % d == 0);
+        let (lhs, op, rhs) = match self.rng.gen_range(0..4) {
+            0 => {
+                let lhs = self.rng.gen_range(1..=20);
+                (lhs, '+', value - lhs)
+            }
+            2 if divisor.is_some() => {
+                let rhs = divisor.unwrap();
+                (value / rhs, '*', rhs)
+            }
+            3 if value.abs() < MAX_VALUE / 10 => {
+                let rhs = self.rng.gen_range(2..=10);
+                (value * rhs, '/', rhs)
+            }
+            _ => {
+                let rhs = self.rng.gen_range(1..=20);
+                (value + rhs, '-', rhs)
+            }
+        };
+        let left_budget = self.rng.gen_range(0..budget - 1);
+        let lhs = self.constant(lhs, left_budget);
+        let rhs = self.constant(rhs, budget - 1 - left_budget);
+        self.lines.push(format!("{}: {} {} {}", name, lhs, op, rhs));
+        name
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    match b {
+        0 => a.abs(),
+        _ => gcd(b, a % b),
+    }
+}
+
+/// About `size` monkeys, where `humn` sits somewhere below one side of
+/// `root`. Part 2 has a single answer between 1 and 1000000, and all divisions
+/// are exact, for both the listed `humn` value and that answer.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut builder = Generator {
+        rng: StdRng::seed_from_u64(seed),
+        names: HashSet::new(),
+        lines: Vec::new(),
+    };
+    let listed = builder.rng.gen_range(1..=5000);
+    let answer = builder.rng.gen_range(1..=1_000_000);
+    builder.lines.push(format!("humn: {}", listed));
+
+    // Build the chain from `humn` up, tracking its value for both inputs.
+    let mut top = "humn".to_string();
+    let (mut v_listed, mut v_answer) = (listed, answer);
+    let steps = (size / 4).max(1);
+    for _ in 0..steps {
+        let budget = builder.rng.gen_range(1..=3);
+        let common = gcd(v_listed, v_answer);
+        let divisor = (2..=10).find(|d| common % d == 0);
+        let big = v_listed.abs().max(v_answer.abs()) > MAX_VALUE / 10;
+
+        let (op, c, humn_left) = match builder.rng.gen_range(0..4) {
+            0 if !big => (
+                '+',
+                builder.rng.gen_range(1..=100),
+                builder.rng.gen_bool(0.5),
+            ),
+            1 if !big => ('*', builder.rng.gen_range(2..=5), builder.rng.gen_bool(0.5)),
+            2 if divisor.is_some() => ('/', divisor.unwrap(), true),
+            _ => (
+                '-',
+                builder.rng.gen_range(1..=100),
+                builder.rng.gen_bool(0.5),
+            ),
+        };
+        let apply = |v: i64| match (op, humn_left) {
+            ('+', _) => v + c,
+            ('*', _) => v * c,
+            ('/', _) => v / c,
+            (_, true) => v - c,
+            (_, false) => c - v,
+        };
+        v_listed = apply(v_listed);
+        v_answer = apply(v_answer);
+
+        let other = builder.constant(c, budget);
+        let name = builder.name();
+        match humn_left {
+            true => builder
+                .lines
+                .push(format!("{}: {} {} {}", name, top, op, other)),
+            false => builder
+                .lines
+                .push(format!("{}: {} {} {}", name, other, op, top)),
+        }
+        top = name;
+    }
+
+    // The other side of `root` has to match the answer.
+    let budget = size.saturating_sub(builder.lines.len() + 1).max(1);
+    let other = builder.constant(v_answer, budget);
+    builder.lines.push(format!("root: {} + {}", top, other));
+
+    builder.lines.shuffle(&mut builder.rng);
+    builder.lines.iter().map(|l| format!("{}\n", l)).collect()
+}
@@ -1,56 +1,72 @@
+use aoc_common::parse::Lines;
+use aoc_common::{trace, Answer, ParseError, Solver};
 use rand::prelude::*;
 use std::collections::{HashMap, VecDeque};
-use std::env;
-use std::fs;
 
-fn get_input_contents() -> String {
-    let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
-    fs::read_to_string(filename).expect("Failed to read file")
-}
+mod generate;
+
+#[derive(Default)]
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input = HashMap<String, Node>;
 
-fn main() {
-    let inp = get_input_contents();
-    let graph = parse_input(&inp);
-    let p1 = solve(&graph);
-    println!("Part 1: {}", p1);
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, graph: &Self::Input) -> Answer {
+        solve(graph).into()
+    }
 
-    let p2 = goal_seeking(&graph);
-    println!("Part 2: {}", p2);
+    fn part_2(&self, graph: &Self::Input) -> Option<Answer> {
+        Some(goal_seeking(graph).into())
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        Some(generate::input(seed, size))
+    }
 }
 
 #[derive(Debug, Clone)]
-struct Task {
+pub struct Task {
     lhs: String,
     rhs: String,
     op: char,
 }
 
 #[derive(Debug, Clone)]
-enum Node {
+pub enum Node {
     Literal(i64),
     Task(Task),
 }
 
-fn parse_input(inp: &str) -> HashMap<String, Node> {
+fn parse_input(inp: &str) -> Result<HashMap<String, Node>, ParseError> {
     let mut res = HashMap::new();
 
-    for line in inp.lines() {
-        let (name, task) = line.split_once(": ").unwrap();
-        let task_parts: Vec<_> = task.split_whitespace().collect();
-        let node = match &task_parts[..] {
-            &[x] => Node::Literal(x.parse::<i64>().unwrap()),
-            &[lhs, op, rhs] => Node::Task(Task {
-                lhs: lhs.to_string(),
-                rhs: rhs.to_string(),
-                op: op.chars().next().unwrap(),
-            }),
-            x => panic!("Failed to parse node {:?}", x),
+    for mut line in Lines::new(inp) {
+        let name = line.word()?;
+        line.tag(": ")?;
+        let node = match line.peek() {
+            Some(c) if c.is_ascii_digit() || c == '-' => Node::Literal(line.number()?),
+            _ => {
+                let lhs = line.word()?;
+                line.tag(" ")?;
+                let op = line.one_of("+-*/")?;
+                line.tag(" ")?;
+                let rhs = line.word()?;
+                Node::Task(Task {
+                    lhs: lhs.to_string(),
+                    rhs: rhs.to_string(),
+                    op,
+                })
+            }
         };
+        line.end()?;
         res.insert(name.to_string(), node);
     }
 
-    res
+    Ok(res)
 }
 
 fn topological_sort(graph: &HashMap<String, Node>) -> Vec<String> {
@@ -98,7 +114,7 @@ fn topological_sort(graph: &HashMap<String, Node>) -> Vec<String> {
 }
 
 fn solve(graph: &HashMap<String, Node>) -> i64 {
-    let sorted_keys = topological_sort(&graph);
+    let sorted_keys = topological_sort(graph);
     let mut vals: HashMap<String, i64> = HashMap::new();
 
     for key in sorted_keys {
@@ -124,7 +140,7 @@ fn solve(graph: &HashMap<String, Node>) -> i64 {
 }
 
 fn goal_seeking(graph: &HashMap<String, Node>) -> i64 {
-    let mut sorted_keys = topological_sort(&graph);
+    let mut sorted_keys = topological_sort(graph);
     let mut vals: HashMap<String, i64> = HashMap::new();
 
     // We're gonna try to minimize the difference of the inputs in `root`:
@@ -150,7 +166,7 @@ fn goal_seeking(graph: &HashMap<String, Node>) -> i64 {
         .unwrap();
 
     for key in sorted_keys.drain(0..drain_until) {
-        println!("Pre-calculating {}", key);
+        trace!("Pre-calculating {}", key);
         match graph.get(&key).unwrap() {
             Node::Literal(x) => vals.insert(key, *x),
             Node::Task(Task { lhs, op, rhs }) => {
@@ -188,6 +204,8 @@ fn _goal_seeking_run(
     task_order: &Vec<String>,
 ) -> i64 {
     vals.insert("humn".to_string(), guess);
+    // Integer division can hide a wrong guess, so only exact answers count.
+    let mut inexact = 0;
 
     for key in task_order {
         match graph.get(key).unwrap() {
@@ -198,10 +216,15 @@ fn _goal_seeking_run(
                     '+' => lhs + rhs,
                     '-' => lhs - rhs,
                     '*' => lhs * rhs,
-                    '/' => lhs / rhs,
+                    '/' => {
+                        if lhs % rhs != 0 {
+                            inexact += 1;
+                        }
+                        lhs / rhs
+                    }
                     '%' => lhs % rhs,
                     // Express the diff as a cost we can minimize
-                    '=' => (lhs - rhs).abs(),
+                    '=' => (lhs - rhs).abs() + inexact,
                     x => panic!("Unknown operator {}", x),
                 };
                 vals.insert(key.to_string(), val)
@@ -228,7 +251,8 @@ fn differential_evolution(
     lower_bound: i64,
     upper_bound: i64,
 ) -> Option<i64> {
-    let mut rng = rand::thread_rng();
+    // A fixed seed, so every run gives the same answer.
+    let mut rng = StdRng::seed_from_u64(2022);
 
     let mut agents: Vec<Agent> = (0..pop_size)
         .map(|x| {
@@ -246,7 +270,7 @@ fn differential_evolution(
 
     while iter < max_iter {
         if iter % 1000 == 0 {
-            println!("{}: {:?}", iter, agents);
+            trace!("{}: {:?}", iter, agents);
         }
         for agent in &agents {
             // Pick 3 agents at random to combine
@@ -268,9 +292,41 @@ fn differential_evolution(
             }
         }
         agents.clear();
-        agents = next_gen.drain(..).collect();
+        agents = std::mem::take(&mut next_gen);
         iter += 1;
     }
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day21.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day21.part_1(&inp), Answer::Int(152));
+        assert_eq!(Day21.part_2(&inp), Some(Answer::Int(301)));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let generated = Day21.generate(seed, 200).unwrap();
+            assert_eq!(generated, Day21.generate(seed, 200).unwrap());
+            let mut inp = Day21.parse(&generated).unwrap();
+            Day21.part_1(&inp);
+
+            // Both sides of `root` match with the found answer.
+            let Some(Answer::Int(answer)) = Day21.part_2(&inp) else {
+                panic!("No answer for seed {}", seed);
+            };
+            inp.insert("humn".to_string(), Node::Literal(answer));
+            if let Some(Node::Task(root)) = inp.get_mut("root") {
+                root.op = '-';
+            }
+            assert_eq!(solve(&inp), 0, "Wrong answer for seed {}", seed);
+        }
+    }
+}
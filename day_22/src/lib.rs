@@ -0,0 +1,476 @@
+use aoc_common::parse::Lines;
+use aoc_common::render::{self, Cell, Color, Frame};
+use aoc_common::{Answer, Grid, ParseError, Solver};
+
+#[derive(Default)]
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input = (Map, Moves);
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, (map, moves): &Self::Input) -> Answer {
+        let mut pos = start_pos(map);
+        do_moves(&mut pos, map, moves, None);
+        pos.password().into()
+    }
+
+    fn part_2(&self, (map, moves): &Self::Input) -> Option<Answer> {
+        let cube = Cube::fold(map)?;
+        let mut pos = start_pos(map);
+        do_moves(&mut pos, map, moves, Some(&cube));
+        Some(pos.password().into())
+    }
+}
+
+fn start_pos(map: &Map) -> Pos {
+    Pos {
+        x: map.row(0).iter().position(|c| *c == '.').unwrap(),
+        y: 0,
+        dir_x: 1,
+        dir_y: 0,
+    }
+}
+
+#[derive(Debug)]
+pub enum Move {
+    TurnCW,
+    TurnCCW,
+    Forward(u64),
+}
+type Map = Grid<char>;
+type Moves = Vec<Move>;
+
+#[derive(Debug, Clone)]
+struct Pos {
+    x: usize,
+    y: usize,
+    dir_x: i32,
+    dir_y: i32,
+}
+
+impl Pos {
+    fn password(&self) -> usize {
+        let facing_idx = match (self.dir_x, self.dir_y) {
+            (1, 0) => 0,
+            (0, 1) => 1,
+            (-1, 0) => 2,
+            (0, -1) => 3,
+            x => panic!("Unexpected facing {:?}", x),
+        };
+        1000 * (self.y + 1) + 4 * (self.x + 1) + facing_idx
+    }
+
+    fn arrow(&self) -> char {
+        match (self.dir_x, self.dir_y) {
+            (1, 0) => '>',
+            (0, 1) => 'v',
+            (-1, 0) => '<',
+            (0, -1) => '^',
+            _ => 'o',
+        }
+    }
+}
+
+fn parse_input(inp: &str) -> Result<(Map, Moves), ParseError> {
+    let mut blocks = Lines::blocks(inp).into_iter();
+
+    let mut rows: Vec<Vec<char>> = blocks
+        .next()
+        .ok_or_else(|| ParseError::at_end(inp, "the map"))?
+        .map(|mut l| {
+            let mut row = Vec::new();
+            while !l.is_empty() {
+                row.push(l.one_of(" .#")?);
+            }
+            Ok(row)
+        })
+        .collect::<Result<_, _>>()?;
+    if !rows[0].contains(&'.') {
+        let row: String = rows[0].iter().collect();
+        return Err(ParseError::new(
+            1,
+            1,
+            "an open tile `.` to start on",
+            format!("`{}`", row.trim()),
+        ));
+    }
+
+    let mut path = blocks
+        .next()
+        .ok_or_else(|| ParseError::at_end(inp, "the path"))?;
+    let mut line = path.expect("the path")?;
+    let mut moves = Vec::new();
+    while !line.is_empty() {
+        match line.peek() {
+            Some(c) if c.is_ascii_digit() => moves.push(Move::Forward(line.number()?)),
+            _ => moves.push(line.symbol("a number, `R` or `L`", |c| match c {
+                'R' => Some(Move::TurnCW),
+                'L' => Some(Move::TurnCCW),
+                _ => None,
+            })?),
+        }
+    }
+    path.end()?;
+    if let Some(line) = blocks.next().and_then(|mut b| b.next()) {
+        return Err(line.error("end of input"));
+    }
+
+    // Ensure all rows of the map are of equal length
+    let width = rows.iter().map(|r| r.len()).max().unwrap();
+    for r in &mut rows {
+        r.resize(width, ' ');
+    }
+
+    Ok((Grid::from_rows(rows), moves))
+}
+
+/// Walks the map, or the `cube` it folds into if given.
+fn do_moves(pos: &mut Pos, map: &Map, moves: &Moves, cube: Option<&Cube>) {
+    let mut drawmap = map.clone();
+
+    for mv in moves {
+        match mv {
+            Move::TurnCW => {
+                let prev_y = pos.dir_y;
+                pos.dir_y = pos.dir_x;
+                pos.dir_x = -prev_y;
+            }
+            Move::TurnCCW => {
+                let prev_x = pos.dir_x;
+                pos.dir_x = pos.dir_y;
+                pos.dir_y = -prev_x;
+            }
+            Move::Forward(n) => match cube {
+                Some(cube) => step_cube(*n, pos, map, cube, &mut drawmap),
+                None if pos.dir_y == 0 => step_horizontal(*n, pos, map),
+                None => step_vertical(*n, pos, map),
+            },
+        }
+        drawmap[(pos.x, pos.y)] = pos.arrow();
+        render::record(|| draw(&drawmap));
+    }
+}
+
+fn draw(map: &Map) -> Frame {
+    Frame::from_grid(map, |c| match c {
+        '.' => Cell::new('.', Color::GREY),
+        '#' => Cell::new('#', Color::WHITE),
+        ' ' => Cell::EMPTY,
+        _ => Cell::new(*c, Color::RED),
+    })
+}
+
+fn step_horizontal(n: u64, pos: &mut Pos, map: &Map) {
+    let row = map.row(pos.y);
+    let width = row.len() as i32;
+
+    for _ in 0..n {
+        let check_pos = pos.x as i32 + pos.dir_x;
+
+        let next_pos = match check_pos {
+            x if pos.dir_x < 0 && (x < 0 || row[x as usize] == ' ') => {
+                let offset = row.iter().rev().position(|c| *c != ' ').unwrap();
+                width as usize - offset - 1
+            }
+            x if pos.dir_x > 0 && (x > width - 1 || row[x as usize] == ' ') => {
+                row.iter().position(|c| *c != ' ').unwrap()
+            }
+            x => x as usize,
+        };
+        match row[next_pos] {
+            '.' => pos.x = next_pos,
+            '#' => return,
+            c => panic!("Unexpected block {c} at {next_pos},{}", pos.y),
+        }
+    }
+}
+
+fn step_vertical(n: u64, pos: &mut Pos, map: &Map) {
+    let height = map.height() as i32;
+
+    for _ in 0..n {
+        let check_pos = pos.y as i32 + pos.dir_y;
+
+        let next_pos = match check_pos {
+            y if pos.dir_y < 0 && (y < 0 || map[(pos.x, y as usize)] == ' ') => {
+                let offset = map.column(pos.x).rev().take_while(|c| **c == ' ').count();
+                height as usize - offset - 1
+            }
+            y if pos.dir_y > 0 && (y > height - 1 || map[(pos.x, y as usize)] == ' ') => {
+                map.column(pos.x).take_while(|c| **c == ' ').count()
+            }
+            y => y as usize,
+        };
+        match map[(pos.x, next_pos)] {
+            '.' => pos.y = next_pos,
+            '#' => return,
+            c => panic!("Unexpected block {c} at {},{next_pos}", pos.x),
+        }
+    }
+}
+
+type Vec3 = [i32; 3];
+
+fn neg([x, y, z]: Vec3) -> Vec3 {
+    [-x, -y, -z]
+}
+
+/// A square of the map, as a face of the cube: which way its right, down and
+/// outside point once the map is folded up.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    x: usize,
+    y: usize,
+    right: Vec3,
+    down: Vec3,
+    normal: Vec3,
+}
+
+impl Face {
+    /// Where the edge on the `dir` side of the face points.
+    fn outward(&self, (dx, dy): (i32, i32)) -> Vec3 {
+        match (dx, dy) {
+            (1, 0) => self.right,
+            (-1, 0) => neg(self.right),
+            (0, 1) => self.down,
+            _ => neg(self.down),
+        }
+    }
+
+    /// The direction along the edge on the `dir` side of the face, in which
+    /// positions on it count up.
+    fn along(&self, (dx, _): (i32, i32)) -> Vec3 {
+        match dx {
+            0 => self.right,
+            _ => self.down,
+        }
+    }
+
+    /// The face that the map folds onto when going `dir` from this one.
+    fn fold(&self, (dx, dy): (i32, i32), x: usize, y: usize) -> Face {
+        let (right, down, normal) = match (dx, dy) {
+            (1, 0) => (neg(self.normal), self.down, self.right),
+            (-1, 0) => (self.normal, self.down, neg(self.right)),
+            (0, 1) => (self.right, neg(self.normal), self.down),
+            _ => (self.right, self.normal, neg(self.down)),
+        };
+        Face {
+            x,
+            y,
+            right,
+            down,
+            normal,
+        }
+    }
+}
+
+const DIRS: [(i32, i32); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+/// The map folded up into a cube, for any of the ways to lay one out flat.
+struct Cube {
+    size: usize,
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    /// None if the map doesn't fold into a cube.
+    fn fold(map: &Map) -> Option<Cube> {
+        let tiles = map.values().filter(|c| **c != ' ').count();
+        let size = (1..=tiles).find(|s| 6 * s * s >= tiles)?;
+        if 6 * size * size != tiles {
+            return None;
+        }
+        let is_face = |x: usize, y: usize| {
+            (0..size).all(|dy| {
+                (0..size).all(|dx| {
+                    map.get((x * size + dx, y * size + dy))
+                        .is_some_and(|c| *c != ' ')
+                })
+            })
+        };
+
+        // Walk the faces from the first, folding each one onto the last.
+        let start = (0..map.width() / size).find(|x| is_face(*x, 0))?;
+        let mut faces = vec![Face {
+            x: start,
+            y: 0,
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+            normal: [0, 0, 1],
+        }];
+        let mut i = 0;
+        while i < faces.len() {
+            let face = faces[i];
+            for dir in DIRS {
+                let (x, y) = (face.x as i32 + dir.0, face.y as i32 + dir.1);
+                if x < 0 || y < 0 || faces.iter().any(|f| (f.x as i32, f.y as i32) == (x, y)) {
+                    continue;
+                }
+                if is_face(x as usize, y as usize) {
+                    faces.push(face.fold(dir, x as usize, y as usize));
+                }
+            }
+            i += 1;
+        }
+        let mut normals: Vec<_> = faces.iter().map(|f| f.normal).collect();
+        normals.sort_unstable();
+        normals.dedup();
+        match faces.len() == 6 && normals.len() == 6 {
+            true => Some(Cube { size, faces }),
+            false => None,
+        }
+    }
+
+    fn face_at(&self, x: usize, y: usize) -> &Face {
+        let (fx, fy) = (x / self.size, y / self.size);
+        self.faces.iter().find(|f| (f.x, f.y) == (fx, fy)).unwrap()
+    }
+
+    /// Where going over the edge of a face from `pos` ends up, and facing
+    /// which way.
+    fn wrap(&self, pos: &Pos) -> Pos {
+        let s = self.size;
+        let dir = (pos.dir_x, pos.dir_y);
+        let from = self.face_at(pos.x, pos.y);
+        let to = self
+            .faces
+            .iter()
+            .find(|f| f.normal == from.outward(dir))
+            .unwrap();
+        // The edge of `to` that touches `from`.
+        let edge = *DIRS
+            .iter()
+            .find(|d| to.outward(**d) == from.normal)
+            .unwrap();
+
+        let offset = match dir.0 {
+            0 => pos.x % s,
+            _ => pos.y % s,
+        };
+        let offset = match to.along(edge) == from.along(dir) {
+            true => offset,
+            false => s - 1 - offset,
+        };
+        let (x, y) = match edge {
+            (1, 0) => (s - 1, offset),
+            (-1, 0) => (0, offset),
+            (0, 1) => (offset, s - 1),
+            _ => (offset, 0),
+        };
+        Pos {
+            x: to.x * s + x,
+            y: to.y * s + y,
+            dir_x: -edge.0,
+            dir_y: -edge.1,
+        }
+    }
+}
+
+fn step_cube(n: u64, pos: &mut Pos, map: &Map, cube: &Cube, drawmap: &mut Map) {
+    for _ in 0..n {
+        let (x, y) = (pos.x as i32 + pos.dir_x, pos.y as i32 + pos.dir_y);
+        let on_map =
+            x >= 0 && y >= 0 && map.get((x as usize, y as usize)).is_some_and(|c| *c != ' ');
+        let next = match on_map {
+            true => Pos {
+                x: x as usize,
+                y: y as usize,
+                ..pos.clone()
+            },
+            false => cube.wrap(pos),
+        };
+
+        match map[(next.x, next.y)] {
+            '.' => {
+                *pos = next;
+                drawmap[(pos.x, pos.y)] = pos.arrow();
+            }
+            '#' => return,
+            c => panic!("Unexpected block {c} at {},{}", next.x, next.y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day22.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day22.part_1(&inp), Answer::UInt(6032));
+    }
+
+    #[test]
+    fn test_example_cube() {
+        let inp = Day22.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day22.part_2(&inp), Some(Answer::UInt(5031)));
+    }
+
+    // Going over an edge and straight back again ends up where it started.
+    fn check_edges(net: &str) {
+        let (map, _) = parse_input(net).unwrap();
+        let cube = Cube::fold(&map).unwrap();
+        for ((x, y), _) in map.iter().filter(|(_, c)| **c != ' ') {
+            for (dx, dy) in DIRS {
+                let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                if nx >= 0
+                    && ny >= 0
+                    && map
+                        .get((nx as usize, ny as usize))
+                        .is_some_and(|c| *c != ' ')
+                {
+                    continue;
+                }
+                let pos = Pos {
+                    x,
+                    y,
+                    dir_x: dx,
+                    dir_y: dy,
+                };
+                let mut over = cube.wrap(&pos);
+                (over.dir_x, over.dir_y) = (-over.dir_x, -over.dir_y);
+                let back = cube.wrap(&over);
+                assert_eq!(
+                    (back.x, back.y, back.dir_x, back.dir_y),
+                    (x, y, -dx, -dy),
+                    "{}",
+                    net
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fold() {
+        // The example, and the layout of the real inputs.
+        check_edges(include_str!("../example.txt"));
+        check_edges(" ..\n .\n..\n.\n\nR\n");
+        check_edges("..\n ..\n  ..\n\nR\n");
+        check_edges(" .\n....\n .\n\nR\n");
+
+        // Folding the real layout back up: over the top of the first face is
+        // the left of the last one.
+        let (map, _) = parse_input(" ..\n .\n..\n.\n\nR\n").unwrap();
+        let cube = Cube::fold(&map).unwrap();
+        let pos = Pos {
+            x: 1,
+            y: 0,
+            dir_x: 0,
+            dir_y: -1,
+        };
+        let over = cube.wrap(&pos);
+        assert_eq!((over.x, over.y, over.dir_x, over.dir_y), (0, 3, 1, 0));
+
+        // Not a net: it has the right number of faces, but two of them fold
+        // onto the same side.
+        for net in ["...\n...\n\nR\n", ".\n...\n  .\n  .\n\nR\n"] {
+            let (map, _) = parse_input(net).unwrap();
+            assert!(Cube::fold(&map).is_none());
+        }
+    }
+}
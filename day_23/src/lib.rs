@@ -0,0 +1,137 @@
+use aoc_common::grid::{self, SparsePos as Pos};
+use aoc_common::render::{self, Cell, Color, Frame};
+use aoc_common::{Answer, Grid, ParseError, Solver, SparseGrid};
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Default)]
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input = Elves;
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, positions: &Self::Input) -> Answer {
+        let mut positions = positions.clone();
+        let _ = simulate(&mut positions, 10);
+        empty_space(&positions).into()
+    }
+
+    fn part_2(&self, positions: &Self::Input) -> Option<Answer> {
+        let mut positions = positions.clone();
+        Some(simulate(&mut positions, usize::MAX).into())
+    }
+}
+
+type Elves = SparseGrid<()>;
+
+enum Dir {
+    N,
+    E,
+    S,
+    W,
+}
+
+fn simulate(positions: &mut Elves, rounds: usize) -> usize {
+    let mut dir_order = VecDeque::from([Dir::N, Dir::S, Dir::W, Dir::E]);
+
+    // <proposed, from>
+    let mut proposed: HashMap<Pos, Vec<Pos>> = HashMap::with_capacity(positions.len());
+
+    for _rnd in 0..rounds {
+        // Proposal phase:
+        for pos in positions.positions() {
+            // Clockwise from the top left:
+            let occupied = grid::neighbors8(pos).map(|p| positions.contains(p));
+
+            // No elves around, stay where we are:
+            if !occupied.iter().any(|o| *o) {
+                continue;
+            }
+
+            for check_dir in &dir_order {
+                let (free, new_pos) = match check_dir {
+                    Dir::N => (!occupied[0..3].iter().any(|o| *o), (pos.0, pos.1 - 1)),
+                    Dir::E => (!occupied[2..5].iter().any(|o| *o), (pos.0 + 1, pos.1)),
+                    Dir::S => (!occupied[4..7].iter().any(|o| *o), (pos.0, pos.1 + 1)),
+                    Dir::W => (
+                        !occupied[6..].iter().any(|o| *o) && !occupied[0],
+                        (pos.0 - 1, pos.1),
+                    ),
+                };
+                if free {
+                    proposed
+                        .entry(new_pos)
+                        .and_modify(|x| x.push(pos))
+                        .or_insert_with(|| vec![pos]);
+                    break;
+                }
+            }
+        }
+
+        let mut any_moved = false;
+        // Move phase:
+        for (to, from) in proposed.drain() {
+            // Only move if exactly 1 elf wants to move there
+            if from.len() == 1 {
+                // Remove the old pos:
+                positions.remove(from[0]);
+                // Add the new pos:
+                positions.insert(to, ());
+                any_moved = true;
+            }
+        }
+        if !any_moved {
+            return _rnd + 1;
+        }
+
+        render::record(|| {
+            Frame::from_sparse(positions, Cell::new('.', Color::GREY), |_| {
+                Cell::new('#', Color::GREEN)
+            })
+        });
+
+        // Rotate phase
+        dir_order.rotate_left(1);
+    }
+
+    rounds
+}
+
+fn empty_space(positions: &Elves) -> usize {
+    let ((min_x, min_y), (max_x, max_y)) = positions.bounds().unwrap();
+    let width = (max_x - min_x + 1) as usize;
+    let height = (max_y - min_y + 1) as usize;
+    width * height - positions.len()
+}
+
+fn parse_input(inp: &str) -> Result<Elves, ParseError> {
+    let grid = Grid::parse(inp, "`#` or `.`", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    let positions: Elves = grid
+        .iter()
+        .filter(|(_, elf)| **elf)
+        .map(|((x, y), _)| ((x as i64, y as i64), ()))
+        .collect();
+    if positions.is_empty() {
+        return Err(ParseError::at_end(inp, "an elf `#`"));
+    }
+    Ok(positions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day23.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day23.part_1(&inp), Answer::UInt(110));
+        assert_eq!(Day23.part_2(&inp), Some(Answer::UInt(20)));
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{parse_input, Day24};
+use aoc_common::Solver;
+use rand::prelude::*;
+
+/// A valley `size` cells wide and a fifth of that high, with the gaps in the
+/// top left and bottom right like the real input. Valleys without a way
+/// there and back again are thrown away, and the next ones get fewer
+/// blizzards.
+pub fn input(seed: u64, size: usize) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let width = size.max(1);
+    let height = (size / 5).max(1);
+
+    for attempt in 0.. {
+        let density = 0.5 / (1.0 + attempt as f64 / 10.0);
+        let valley = valley(&mut rng, width, height, density);
+        if solvable(&valley) {
+            return valley;
+        }
+    }
+    unreachable!()
+}
+
+fn valley(rng: &mut StdRng, width: usize, height: usize, density: f64) -> String {
+    let mut res = String::with_capacity((width + 3) * (height + 2));
+    res.push_str("#.");
+    res.push_str(&"#".repeat(width));
+    res.push('\n');
+    for _ in 0..height {
+        res.push('#');
+        for x in 0..width {
+            // Nothing blows through the gaps, or there'd be no way out.
+            let kinds: &[char] = match x == 0 || x == width - 1 {
+                true => &['<', '>'],
+                false => &['<', '>', '^', 'v'],
+            };
+            match rng.gen_bool(density) {
+                true => res.push(*kinds.choose(rng).unwrap()),
+                false => res.push('.'),
+            }
+        }
+        res.push_str("#\n");
+    }
+    res.push_str(&"#".repeat(width));
+    res.push_str(".#\n");
+    res
+}
+
+/// Whether there's a way there, back and there again.
+fn solvable(valley: &str) -> bool {
+    parse_input(valley).is_ok_and(|valley| Day24.part_2(&valley).is_some())
+}
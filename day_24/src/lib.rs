@@ -0,0 +1,206 @@
+use aoc_common::grid::{self, SparsePos as Pos};
+use aoc_common::parse::Scanner;
+use aoc_common::render::{self, Cell, Color, Frame};
+use aoc_common::{search, Answer, Grid, ParseError, Solver};
+use std::collections::HashSet;
+
+mod generate;
+
+#[derive(Default)]
+pub struct Day24;
+
+impl Solver for Day24 {
+    // The blizzard map over time, start, end, the period of the blizzards and
+    // the time of the first trip to the end.
+    type Input = (HashSet<PosAtTime>, Pos, Pos, i64, usize);
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        parse_input(inp)
+    }
+
+    fn part_1(&self, (_, _, _, _, p1): &Self::Input) -> Answer {
+        (*p1).into()
+    }
+
+    fn part_2(&self, (map, start, end, period, p1): &Self::Input) -> Option<Answer> {
+        let back_to_start = best_path(map, (end.0, end.1, *p1 as i64), *start, *period)?;
+        let second_time_to_end = best_path(
+            map,
+            (start.0, start.1, (p1 + back_to_start) as i64),
+            *end,
+            *period,
+        )?;
+        Some((p1 + back_to_start + second_time_to_end).into())
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        Some(generate::input(seed, size))
+    }
+}
+
+// Brilliant idea shamelessly stolen off of Reddit
+type PosAtTime = (i64, i64, i64);
+
+type Valley = (HashSet<PosAtTime>, Pos, Pos, i64, usize);
+
+fn parse_input(input: &str) -> Result<Valley, ParseError> {
+    let grid = Grid::parse(input, "a wall, ground or blizzard", |c| {
+        "#.<>^v".contains(c).then_some(c)
+    })?;
+    let width = grid.width() as i64;
+    let height = grid.height() as i64;
+    // The valley needs walls all around, with a gap at the top and bottom.
+    let gap = |y: usize| {
+        grid.row(y).iter().position(|c| *c == '.').ok_or_else(|| {
+            Scanner::new(y + 1, input.lines().nth(y).unwrap()).error("a gap `.` in the wall")
+        })
+    };
+    let start = (gap(0)? as i64, 0);
+    let end = (gap(grid.height() - 1)? as i64, height - 1);
+    if width < 3 || height < 3 {
+        return Err(ParseError::at_end(input, "a valley surrounded by walls"));
+    }
+    let period = lcm(width - 2, height - 2);
+
+    let mut map: HashSet<(i64, i64, i64)> = grid
+        .iter()
+        .filter(|(_, c)| **c != '.')
+        .map(|((x, y), c)| (x as i64, y as i64, *c))
+        .flat_map(|(x, y, c)| match c {
+            '#' => (0..period).map(|z| (x, y, z)).collect::<Vec<_>>(),
+            '<' => (0..period)
+                .map(|z| ((x - 1 - z).rem_euclid(width - 2) + 1, y, z))
+                .collect::<Vec<_>>(),
+            '>' => (0..period)
+                .map(|z| ((x - 1 + z).rem_euclid(width - 2) + 1, y, z))
+                .collect::<Vec<_>>(),
+            '^' => (0..period)
+                .map(|z| (x, (y - 1 - z).rem_euclid(height - 2) + 1, z))
+                .collect::<Vec<_>>(),
+            _ => (0..period)
+                .map(|z| (x, (y - 1 + z).rem_euclid(height - 2) + 1, z))
+                .collect::<Vec<_>>(),
+        })
+        .collect();
+
+    // Prevent simply walking 'around' the map.
+    for z in 0..period {
+        map.insert((start.0, start.1 - 1, z));
+        map.insert((end.0, end.1 + 1, z));
+    }
+
+    // Whether the blizzards ever let anyone through, which is part 1.
+    let Some(p1) = best_path(&map, (start.0, start.1, 0), end, period) else {
+        let expected = "a gap `.` that can be reached from the one at the top";
+        return Err(ParseError::new(
+            end.1 as usize + 1,
+            end.0 as usize + 1,
+            expected,
+            "`.`",
+        ));
+    };
+
+    Ok((map, start, end, period, p1))
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    let mut a = a.abs();
+    let mut b = b.abs();
+
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+
+    a
+}
+
+fn lcm(a: i64, b: i64) -> i64 {
+    (a * b).abs() / gcd(a, b)
+}
+
+fn clear_neighbors(map: &HashSet<PosAtTime>, (x, y, z): PosAtTime, period: i64) -> Vec<PosAtTime> {
+    let next_moment = (z + 1) % period;
+    // Move to any side, or wait where we are.
+    grid::neighbors4((x, y))
+        .into_iter()
+        .chain([(x, y)])
+        .map(|(x, y)| (x, y, next_moment))
+        .filter(|p| !map.contains(p))
+        .collect()
+}
+
+fn best_path(map: &HashSet<PosAtTime>, start: PosAtTime, end: Pos, period: i64) -> Option<usize> {
+    let path = search::bfs(
+        [start],
+        |pos| clear_neighbors(map, *pos, period),
+        |pos| (pos.0, pos.1) == end,
+    )?;
+    if render::is_recording() {
+        // The walls run along the right edge of the valley.
+        let width = map.iter().map(|p| p.0).max().unwrap() + 1;
+        let height = start.1.max(end.1) + 1;
+        for pos in &path.nodes {
+            render::record(|| draw(map, *pos, width, height));
+        }
+    }
+    Some(path.cost)
+}
+
+fn draw(map: &HashSet<PosAtTime>, (ex, ey, z): PosAtTime, width: i64, height: i64) -> Frame {
+    let mut valley = Grid::new(width as usize, height as usize, Cell::EMPTY);
+    for y in 0..height {
+        for x in 0..width {
+            let wall = x == 0 || y == 0 || x == width - 1 || y == height - 1;
+            valley[(x as usize, y as usize)] = match map.contains(&(x, y, z)) {
+                _ if (x, y) == (ex, ey) => Cell::new('E', Color::RED),
+                true if wall => Cell::new('#', Color::WHITE),
+                true => Cell::new('*', Color::BLUE),
+                false => Cell::new('.', Color::GREY),
+            };
+        }
+    }
+    Frame::new(valley)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day24.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day24.part_1(&inp), Answer::UInt(18));
+        assert_eq!(Day24.part_2(&inp), Some(Answer::UInt(54)));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        for (inp, err) in [
+            (
+                "#.###\n#.#.#\n###.#\n",
+                "line 3, column 4: expected a gap `.` that can be reached from the one at the top, found `.`",
+            ),
+            // Forever blocked by a blizzard that wraps onto itself.
+            (
+                "#.#\n#>#\n#.#\n",
+                "line 3, column 2: expected a gap `.` that can be reached from the one at the top, found `.`",
+            ),
+        ] {
+            assert_eq!(Day24.parse(inp).unwrap_err().to_string(), err);
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let generated = Day24.generate(seed, 20).unwrap();
+            assert_eq!(generated, Day24.generate(seed, 20).unwrap());
+            assert_eq!(generated.lines().count(), 4 + 2);
+            assert!(generated.lines().all(|l| l.len() == 20 + 2));
+            let inp = Day24.parse(&generated).unwrap();
+            Day24.part_2(&inp);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use aoc_common::parse::{Lines, Scanner};
+use aoc_common::{stream, Answer, ParseError, Solver, StreamError};
+use std::io::BufRead;
+
+#[derive(Default)]
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input = Vec<Snafu>;
+
+    fn parse(&self, inp: &str) -> Result<Self::Input, ParseError> {
+        Lines::new(inp).map(|mut l| parse_snafu(&mut l)).collect()
+    }
+
+    fn part_1(&self, numbers: &Self::Input) -> Answer {
+        let sum_dec: i64 = numbers.iter().cloned().map(i64::from).sum();
+        let sum_snafu: Snafu = sum_dec.into();
+        sum_snafu.0.into()
+    }
+
+    fn part_2(&self, _input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    fn stream(
+        &self,
+        input: &mut dyn BufRead,
+    ) -> Option<Result<(Answer, Option<Answer>), StreamError>> {
+        let mut sum_dec: i64 = 0;
+        let res = stream::lines(input, |mut l| {
+            sum_dec += i64::from(parse_snafu(&mut l)?);
+            Ok(())
+        });
+        Some(res.map(|_| (Snafu::from(sum_dec).0.into(), None)))
+    }
+}
+
+fn parse_snafu(l: &mut Scanner) -> Result<Snafu, ParseError> {
+    let digits = l.rest();
+    loop {
+        l.one_of("210-=")?;
+        if l.is_empty() {
+            break;
+        }
+    }
+    Ok(Snafu(digits.to_string()))
+}
+
+#[derive(Debug, Clone)]
+pub struct Snafu(String);
+
+impl From<Snafu> for i64 {
+    fn from(item: Snafu) -> Self {
+        item.0
+            .chars()
+            .rev()
+            .enumerate()
+            .map(|(i, c)| {
+                let digit = match c {
+                    x if x.is_digit(3) => x.to_digit(10).unwrap() as i64,
+                    '-' => -1,
+                    '=' => -2,
+                    x => panic!("Invalid SNAFU digit {x}"),
+                };
+                5i64.pow(i as u32) * digit
+            })
+            .sum()
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(mut item: i64) -> Self {
+        let mut res = Vec::new();
+        while item > 0 {
+            match item % 5 {
+                x @ (0..=2) => res.push(char::from_digit(x as u32, 10).unwrap()),
+                3 => {
+                    res.push('=');
+                    item += 2;
+                }
+                4 => {
+                    res.push('-');
+                    item += 1
+                }
+                _ => panic!(),
+            };
+
+            item /= 5;
+        }
+
+        Snafu(res.into_iter().rev().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let inp = Day25.parse(include_str!("../example.txt")).unwrap();
+        assert_eq!(Day25.part_1(&inp), Answer::Text("2=-1=0".to_string()));
+        assert_eq!(Day25.part_2(&inp), None);
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut inp = include_str!("../example.txt").as_bytes();
+        let (p1, p2) = Day25.stream(&mut inp).unwrap().unwrap();
+        assert_eq!(p1, Answer::Text("2=-1=0".to_string()));
+        assert_eq!(p2, None);
+    }
+}